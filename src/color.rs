@@ -0,0 +1,511 @@
+//! provides a typed color which can be used as css value
+
+use crate::units::format_number;
+use crate::value::impl_css_value;
+use named::NAMED_COLORS;
+use std::fmt;
+use std::str::FromStr;
+
+pub use modern::{ColorSpace, ModernColor};
+
+mod modern;
+mod named;
+pub(crate) mod space;
+
+/// A color in the sRGB color space with an alpha channel.
+///
+/// It is rendered as `rgb(r, g, b)`, or `rgba(r, g, b, a)` when it is not fully opaque.
+/// ```rust
+/// use jss::prelude::*;
+///
+/// let css = jss!(
+///     ".layer": {
+///         color: Color::hsl(0.0, 100.0, 50.0),
+///         background_color: Color::rgba(0, 0, 255, 0.5),
+///         border_color: "#00ff00".parse::<Color>().unwrap(),
+///     },
+/// );
+/// let expected = ".layer{color:rgb(255, 0, 0);background-color:rgba(0, 0, 255, 0.5);border-color:rgb(0, 255, 0);}";
+/// assert_eq!(expected, css);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    /// red, green and blue channels in the range of 0.0..=1.0
+    red: f64,
+    green: f64,
+    blue: f64,
+    /// opacity in the range of 0.0..=1.0
+    alpha: f64,
+}
+
+/// The error returned when a string can not be parsed into a color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl Color {
+    /// create an opaque color from the red, green and blue channels
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    /// create a color from the red, green, blue channels and the opacity in the range of 0.0..=1.0
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self::from_srgb(
+            f64::from(red) / 255.0,
+            f64::from(green) / 255.0,
+            f64::from(blue) / 255.0,
+            alpha,
+        )
+    }
+
+    /// create an opaque color from the hue in degrees,
+    /// saturation and lightness in percent (0.0..=100.0)
+    pub fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::hsla(hue, saturation, lightness, 1.0)
+    }
+
+    /// create a color from the hue in degrees,
+    /// saturation and lightness in percent (0.0..=100.0) and the opacity in the range of 0.0..=1.0
+    pub fn hsla(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let (red, green, blue) = hsl_to_srgb(
+            hue,
+            saturation.clamp(0.0, 100.0) / 100.0,
+            lightness.clamp(0.0, 100.0) / 100.0,
+        );
+        Self::from_srgb(red, green, blue, alpha)
+    }
+
+    /// create an opaque color from the hue in degrees,
+    /// whiteness and blackness in percent (0.0..=100.0)
+    pub fn hwb(hue: f64, whiteness: f64, blackness: f64) -> Self {
+        let whiteness = whiteness.clamp(0.0, 100.0) / 100.0;
+        let blackness = blackness.clamp(0.0, 100.0) / 100.0;
+        if whiteness + blackness >= 1.0 {
+            let gray = whiteness / (whiteness + blackness);
+            return Self::from_srgb(gray, gray, gray, 1.0);
+        }
+        let (red, green, blue) = hsl_to_srgb(hue, 1.0, 0.5);
+        let scale = 1.0 - whiteness - blackness;
+        Self::from_srgb(
+            red * scale + whiteness,
+            green * scale + whiteness,
+            blue * scale + whiteness,
+            1.0,
+        )
+    }
+
+    /// parse a hex color in the form of `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`,
+    /// the leading `#` is optional
+    pub fn hex(hex: &str) -> Result<Self, ParseColorError> {
+        let err = || ParseColorError::new(hex);
+        let hex = hex.trim();
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(err());
+        }
+        let channel = |i: usize, len: usize| {
+            let v = u8::from_str_radix(&digits[i * len..(i + 1) * len], 16).map_err(|_| err())?;
+            Ok(if len == 1 { v * 17 } else { v })
+        };
+        let len = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return Err(err()),
+        };
+        let alpha = if len * 4 == digits.len() {
+            f64::from(channel(3, len)?) / 255.0
+        } else {
+            1.0
+        };
+        Ok(Self::rgba(
+            channel(0, len)?,
+            channel(1, len)?,
+            channel(2, len)?,
+            alpha,
+        ))
+    }
+
+    /// lookup the css named color, including `transparent`
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Self::rgba(0, 0, 0, 0.0));
+        }
+        NAMED_COLORS
+            .get(name.as_str())
+            .map(|(red, green, blue)| Self::rgb(*red, *green, *blue))
+    }
+
+    /// the red channel
+    pub fn red(&self) -> u8 {
+        to_u8(self.red)
+    }
+
+    /// the green channel
+    pub fn green(&self) -> u8 {
+        to_u8(self.green)
+    }
+
+    /// the blue channel
+    pub fn blue(&self) -> u8 {
+        to_u8(self.blue)
+    }
+
+    /// the opacity in the range of 0.0..=1.0
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// the hex notation of this color, `#rrggbb` or `#rrggbbaa` when it is not fully opaque
+    pub fn to_hex(&self) -> String {
+        let hex = format!("#{:02x}{:02x}{:02x}", self.red(), self.green(), self.blue());
+        if self.alpha < 1.0 {
+            format!("{}{:02x}", hex, to_u8(self.alpha))
+        } else {
+            hex
+        }
+    }
+
+    /// make the color lighter by adding `amount` (0.0..=1.0) to its oklch lightness
+    /// ```rust
+    /// use jss::color::Color;
+    ///
+    /// assert_eq!("rgb(65, 135, 255)", Color::rgb(0, 85, 210).lighten(0.15).to_string());
+    /// ```
+    pub fn lighten(&self, amount: f64) -> Self {
+        let (l, c, h) = self.to_oklch();
+        Self::from_oklch((l + amount, c, h), self.alpha)
+    }
+
+    /// make the color darker by subtracting `amount` (0.0..=1.0) from its oklch lightness
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// make the color more vivid by increasing its oklch chroma by `amount`
+    /// relative to the current chroma, ie: `0.5` makes it 50% more saturated.
+    /// Grays stay gray.
+    pub fn saturate(&self, amount: f64) -> Self {
+        let (l, c, h) = self.to_oklch();
+        Self::from_oklch((l, (c * (1.0 + amount)).max(0.0), h), self.alpha)
+    }
+
+    /// make the color duller by decreasing its oklch chroma by `amount`
+    /// relative to the current chroma, ie: `1.0` turns it into a gray of the same lightness.
+    pub fn desaturate(&self, amount: f64) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// mix this color with `other` in the oklab color space,
+    /// `weight` (0.0..=1.0) is the proportion of `other` in the result
+    /// ```rust
+    /// use jss::color::Color;
+    ///
+    /// let white = Color::rgb(255, 255, 255);
+    /// let black = Color::rgb(0, 0, 0);
+    /// assert_eq!("rgb(255, 255, 255)", black.mix(&white, 1.0).to_string());
+    /// assert_eq!("rgb(99, 99, 99)", black.mix(&white, 0.5).to_string());
+    /// ```
+    pub fn mix(&self, other: &Color, weight: f64) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let lerp = |a: f64, b: f64| a + (b - a) * weight;
+        let (l1, a1, b1) = space::srgb_to_oklab(self.to_srgb());
+        let (l2, a2, b2) = space::srgb_to_oklab(other.to_srgb());
+        let (r, g, b) = space::oklab_to_srgb((lerp(l1, l2), lerp(a1, a2), lerp(b1, b2)));
+        Self::from_srgb(r, g, b, lerp(self.alpha, other.alpha))
+    }
+
+    /// the same color with the opacity set to `alpha` (0.0..=1.0)
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        Self::from_srgb(self.red, self.green, self.blue, alpha)
+    }
+
+    /// the color on the opposite side of the oklch hue wheel
+    pub fn complement(&self) -> Self {
+        let (l, c, h) = self.to_oklch();
+        Self::from_oklch((l, c, h + 180.0), self.alpha)
+    }
+
+    /// the inverse of each of the red, green and blue channels
+    pub fn invert(&self) -> Self {
+        Self::from_srgb(
+            1.0 - self.red,
+            1.0 - self.green,
+            1.0 - self.blue,
+            self.alpha,
+        )
+    }
+
+    /// the WCAG relative luminance of this color, from 0.0 for black to 1.0 for white
+    /// [Reference](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+    pub fn relative_luminance(&self) -> f64 {
+        0.2126 * space::srgb_to_linear(self.red)
+            + 0.7152 * space::srgb_to_linear(self.green)
+            + 0.0722 * space::srgb_to_linear(self.blue)
+    }
+
+    /// the WCAG contrast ratio in between this color and `other`,
+    /// from 1.0 for the same colors to 21.0 for black and white.
+    /// The opacity of the colors is not taken into account.
+    /// [Reference](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+    /// ```rust
+    /// use jss::color::Color;
+    ///
+    /// let ratio = Color::rgb(0, 0, 0).contrast_ratio(&Color::rgb(255, 255, 255));
+    /// assert_eq!(21.0, ratio);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// paint this color on top of `background`, the way browsers blend translucent colors
+    pub(crate) fn over(&self, background: &Color) -> Self {
+        let blend = |fg: f64, bg: f64| fg * self.alpha + bg * (1.0 - self.alpha);
+        Self::from_srgb(
+            blend(self.red, background.red),
+            blend(self.green, background.green),
+            blend(self.blue, background.blue),
+            background.alpha,
+        )
+    }
+
+    /// the oklch lightness (0.0..=1.0), chroma and hue in degrees of this color
+    pub(crate) fn to_oklch(self) -> (f64, f64, f64) {
+        space::to_polar(space::srgb_to_oklab(self.to_srgb()))
+    }
+
+    /// create a color from oklch, reducing the chroma if it doesn't fit in sRGB
+    pub(crate) fn from_oklch(lch: (f64, f64, f64), alpha: f64) -> Self {
+        let (r, g, b) = space::gamut_map(lch, space::oklab_to_srgb);
+        Self::from_srgb(r, g, b, alpha)
+    }
+
+    /// the sRGB channels in the range of 0.0..=1.0
+    pub(crate) fn to_srgb(self) -> (f64, f64, f64) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// create a color from sRGB channels in the range of 0.0..=1.0
+    pub(crate) fn from_srgb(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red: red.clamp(0.0, 1.0),
+            green: green.clamp(0.0, 1.0),
+            blue: blue.clamp(0.0, 1.0),
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// the sRGB fallback of a value written in one of the modern color notations,
+/// `None` if the value is not a modern color or it can not be parsed
+pub(crate) fn fallback(value: &str) -> Option<Color> {
+    if modern::is_modern(value) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// convert hsl with saturation and lightness in the range of 0.0..=1.0 into sRGB channels
+fn hsl_to_srgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let hue = hue.rem_euclid(360.0);
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (f(0.0), f(8.0), f(4.0))
+}
+
+fn to_u8(channel: f64) -> u8 {
+    (channel * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// write the number rounded to the decimal places using [`format_number`],
+/// so floating point noise and `-0` don't end up in the css
+///
+/// # Panics
+/// when the number is NaN or infinity
+pub(crate) fn number(v: f64, decimals: usize) -> String {
+    format_number(v, decimals).unwrap_or_else(|e| panic!("{}", e))
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.alpha < 1.0 {
+            write!(
+                f,
+                "rgba({}, {}, {}, {})",
+                self.red(),
+                self.green(),
+                self.blue(),
+                number(self.alpha, 3)
+            )
+        } else {
+            write!(f, "rgb({}, {}, {})", self.red(), self.green(), self.blue())
+        }
+    }
+}
+
+impl_css_value!(Color);
+
+impl ParseColorError {
+    pub(crate) fn new(input: &str) -> Self {
+        Self {
+            input: input.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color: `{}`", self.input)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parse a color from any of the css notations:
+/// hex, named colors, `rgb()`, `rgba()`, `hsl()`, `hsla()` and `hwb()`,
+/// using either the legacy comma separated or the space separated syntax.
+/// The modern `oklch()`, `oklab()`, `lab()`, `lch()` and `color-mix()` are parsed
+/// into their closest sRGB color.
+/// ```rust
+/// use jss::color::Color;
+///
+/// assert_eq!(Ok(Color::rgb(255, 0, 0)), "red".parse());
+/// assert_eq!(Ok(Color::rgb(255, 0, 0)), "#f00".parse());
+/// assert_eq!(Ok(Color::rgba(255, 0, 0, 0.5)), "rgb(255 0 0 / 50%)".parse());
+/// assert_eq!(Ok(Color::hsl(120.0, 100.0, 25.0)), "hsl(120deg, 100%, 25%)".parse());
+/// let accent: Color = "oklch(62.8% 0.2577 29.23)".parse().unwrap();
+/// assert_eq!("rgb(255, 0, 0)", accent.to_string());
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim().to_ascii_lowercase();
+        if input.starts_with('#') {
+            return Color::hex(&input);
+        }
+        let err = || ParseColorError::new(s);
+        if modern::is_modern(&input) {
+            return modern::parse(&input).ok_or_else(err);
+        }
+        let Some((name, args)) = function_args(&input) else {
+            return Color::named(&input).ok_or_else(err);
+        };
+        let alpha = |i: usize| match args.get(i) {
+            Some(arg) => parse_alpha(arg).ok_or_else(err),
+            None => Ok(1.0),
+        };
+        match (name, args.len()) {
+            ("rgb" | "rgba", 3 | 4) => {
+                let channel = |i: usize| parse_channel(args[i]).ok_or_else(err);
+                Ok(Color::from_srgb(
+                    channel(0)?,
+                    channel(1)?,
+                    channel(2)?,
+                    alpha(3)?,
+                ))
+            }
+            ("hsl" | "hsla", 3 | 4) => Ok(Color::hsla(
+                parse_hue(args[0]).ok_or_else(err)?,
+                parse_percent(args[1]).ok_or_else(err)?,
+                parse_percent(args[2]).ok_or_else(err)?,
+                alpha(3)?,
+            )),
+            ("hwb", 3 | 4) => {
+                let color = Color::hwb(
+                    parse_hue(args[0]).ok_or_else(err)?,
+                    parse_percent(args[1]).ok_or_else(err)?,
+                    parse_percent(args[2]).ok_or_else(err)?,
+                );
+                Ok(Color {
+                    alpha: alpha(3)?.clamp(0.0, 1.0),
+                    ..color
+                })
+            }
+            _ => Err(err()),
+        }
+    }
+}
+
+/// split a css function notation into its name and arguments,
+/// the arguments are either all separated by commas, ie: `rgb(1, 2, 3, 0.5)`,
+/// or by spaces with the alpha after a `/`, ie: `rgb(1 2 3 / 0.5)`
+pub(crate) fn function_args(input: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, rest) = input.split_once('(')?;
+    let arguments = rest.trim_end().strip_suffix(')')?;
+    let args: Vec<&str> = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    let separators_are_valid = if arguments.contains(',') {
+        !arguments.contains('/') && arguments.matches(',').count() + 1 == args.len()
+    } else {
+        match arguments.split_once('/') {
+            Some((components, alpha)) => {
+                components.split_whitespace().count() == 3 && alpha.split_whitespace().count() == 1
+            }
+            None => args.len() <= 3,
+        }
+    };
+    if !separators_are_valid {
+        return None;
+    }
+    Some((name.trim(), args))
+}
+
+/// parse a number, `nan` and `inf` are not numbers in css
+pub(crate) fn parse_number(arg: &str) -> Option<f64> {
+    arg.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// parse a number which may have a percent sign, in which case it is divided by 100
+pub(crate) fn parse_number_or_percent(arg: &str) -> Option<f64> {
+    if let Some(percent) = arg.strip_suffix('%') {
+        parse_number(percent).map(|v| v / 100.0)
+    } else {
+        parse_number(arg)
+    }
+}
+
+/// parse an rgb channel which is either 0..=255 or a percentage
+fn parse_channel(arg: &str) -> Option<f64> {
+    if arg.ends_with('%') {
+        parse_number_or_percent(arg)
+    } else {
+        parse_number(arg).map(|v| v / 255.0)
+    }
+}
+
+/// parse the alpha channel which is either 0.0..=1.0 or a percentage
+pub(crate) fn parse_alpha(arg: &str) -> Option<f64> {
+    parse_number_or_percent(arg)
+}
+
+/// parse a percentage, where the percent sign is optional
+fn parse_percent(arg: &str) -> Option<f64> {
+    parse_number(arg.trim_end_matches('%'))
+}
+
+/// parse a hue into degrees, the angle unit is optional
+pub(crate) fn parse_hue(arg: &str) -> Option<f64> {
+    let (number, factor) = if let Some(v) = arg.strip_suffix("deg") {
+        (v, 1.0)
+    } else if let Some(v) = arg.strip_suffix("grad") {
+        (v, 0.9)
+    } else if let Some(v) = arg.strip_suffix("rad") {
+        (v, 180.0 / std::f64::consts::PI)
+    } else if let Some(v) = arg.strip_suffix("turn") {
+        (v, 360.0)
+    } else {
+        (arg, 1.0)
+    };
+    parse_number(number).map(|v| v * factor)
+}
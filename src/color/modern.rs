@@ -0,0 +1,384 @@
+//! colors in the modern css color spaces, these are rendered as they are written
+//! while an sRGB approximation is computed to be used as fallback in older browsers
+
+use super::{function_args, number, parse_alpha, parse_hue, parse_number, space, Color};
+use crate::value::{impl_css_value, split_top_level};
+use std::fmt;
+
+/// The color space in which `color-mix()` interpolates the colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// gamma encoded sRGB
+    Srgb,
+    /// linear light sRGB
+    SrgbLinear,
+    /// the perceptual oklab
+    Oklab,
+    /// the polar form of oklab
+    Oklch,
+    /// CIE lab
+    Lab,
+    /// the polar form of CIE lab
+    Lch,
+}
+
+/// A color written using `oklch()`, `oklab()`, `lab()`, `lch()` or `color-mix()`.
+///
+/// It is rendered as it is written, the [`fallback`](ModernColor::fallback) is the closest
+/// sRGB color which can be used for browsers that doesn't support the modern notation.
+/// ```rust
+/// use jss::prelude::*;
+///
+/// let accent = oklch(0.628, 0.2577, 29.23);
+/// assert_eq!("oklch(0.628 0.2577 29.23)", accent.to_string());
+/// assert_eq!("rgb(255, 0, 0)", accent.fallback().to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModernColor {
+    notation: Notation,
+    fallback: Color,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Notation {
+    /// one of the color functions with its 3 components
+    Function {
+        name: &'static str,
+        components: [f64; 3],
+        alpha: f64,
+    },
+    /// a color in sRGB, written as `rgb()` or `rgba()`
+    Srgb(Color),
+    /// an already formatted color
+    Raw(String),
+}
+
+impl ModernColor {
+    /// the color in the oklch color space,
+    /// lightness in the range of 0.0..=1.0, chroma typically up to 0.4 and the hue in degrees
+    pub fn oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        let (r, g, b) = space::gamut_map((lightness, chroma, hue), space::oklab_to_srgb);
+        Self::function(
+            "oklch",
+            [lightness, chroma, hue],
+            Color::from_srgb(r, g, b, 1.0),
+        )
+    }
+
+    /// the color in the oklab color space,
+    /// lightness in the range of 0.0..=1.0 and the a and b axes typically within -0.4..=0.4
+    pub fn oklab(lightness: f64, a: f64, b: f64) -> Self {
+        let (r, g, b_) = space::gamut_map(space::to_polar((lightness, a, b)), space::oklab_to_srgb);
+        Self::function("oklab", [lightness, a, b], Color::from_srgb(r, g, b_, 1.0))
+    }
+
+    /// the color in the CIE lab color space,
+    /// lightness in the range of 0.0..=100.0 and the a and b axes typically within -125.0..=125.0
+    pub fn lab(lightness: f64, a: f64, b: f64) -> Self {
+        let (r, g, b_) = space::gamut_map(space::to_polar((lightness, a, b)), space::lab_to_srgb);
+        Self::function("lab", [lightness, a, b], Color::from_srgb(r, g, b_, 1.0))
+    }
+
+    /// the color in the CIE lch color space,
+    /// lightness in the range of 0.0..=100.0, chroma typically up to 150.0 and the hue in degrees
+    pub fn lch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        let (r, g, b) = space::gamut_map((lightness, chroma, hue), space::lab_to_srgb);
+        Self::function(
+            "lch",
+            [lightness, chroma, hue],
+            Color::from_srgb(r, g, b, 1.0),
+        )
+    }
+
+    /// mix 2 colors in the color space, `weight` (0.0..=1.0) is the proportion of `second`
+    pub fn color_mix(
+        space: ColorSpace,
+        first: impl Into<ModernColor>,
+        second: impl Into<ModernColor>,
+        weight: f64,
+    ) -> Self {
+        let first = first.into();
+        let second = second.into();
+        let weight = weight.clamp(0.0, 1.0);
+        let css = if weight == 0.5 {
+            format!("color-mix(in {}, {}, {})", space, first, second)
+        } else {
+            format!(
+                "color-mix(in {}, {}, {} {}%)",
+                space,
+                first,
+                second,
+                number(weight * 100.0, 4)
+            )
+        };
+        Self {
+            notation: Notation::Raw(css),
+            fallback: mix_in(space, first.fallback, second.fallback, weight),
+        }
+    }
+
+    /// the same color with the opacity set to `alpha` (0.0..=1.0)
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        match &self.notation {
+            Notation::Function {
+                name, components, ..
+            } => Self {
+                notation: Notation::Function {
+                    name,
+                    components: *components,
+                    alpha,
+                },
+                fallback: self.fallback.with_alpha(alpha),
+            },
+            Notation::Srgb(color) => Self::from(color.with_alpha(alpha)),
+            // mixing with transparent is the only way to change the opacity of an arbitrary color
+            Notation::Raw(css) => Self {
+                notation: Notation::Raw(format!(
+                    "color-mix(in srgb, {} {}%, transparent)",
+                    css,
+                    number(alpha * 100.0, 4)
+                )),
+                fallback: self.fallback.with_alpha(self.fallback.alpha() * alpha),
+            },
+        }
+    }
+
+    /// the closest color in sRGB, this can be used as fallback for older browsers
+    pub fn fallback(&self) -> Color {
+        self.fallback
+    }
+
+    fn function(name: &'static str, components: [f64; 3], fallback: Color) -> Self {
+        Self {
+            notation: Notation::Function {
+                name,
+                components,
+                alpha: 1.0,
+            },
+            fallback,
+        }
+    }
+}
+
+impl From<Color> for ModernColor {
+    fn from(color: Color) -> Self {
+        Self {
+            notation: Notation::Srgb(color),
+            fallback: color,
+        }
+    }
+}
+
+impl fmt::Display for ModernColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.notation {
+            Notation::Function {
+                name,
+                components,
+                alpha,
+            } => {
+                write!(
+                    f,
+                    "{}({} {} {}",
+                    name,
+                    number(components[0], 4),
+                    number(components[1], 4),
+                    number(components[2], 4)
+                )?;
+                if *alpha < 1.0 {
+                    write!(f, " / {}", number(*alpha, 3))?;
+                }
+                write!(f, ")")
+            }
+            Notation::Srgb(color) => write!(f, "{}", color),
+            Notation::Raw(css) => write!(f, "{}", css),
+        }
+    }
+}
+
+impl_css_value!(ModernColor);
+
+impl fmt::Display for ColorSpace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ColorSpace::Srgb => "srgb",
+            ColorSpace::SrgbLinear => "srgb-linear",
+            ColorSpace::Oklab => "oklab",
+            ColorSpace::Oklch => "oklch",
+            ColorSpace::Lab => "lab",
+            ColorSpace::Lch => "lch",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl ColorSpace {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "srgb" => Some(ColorSpace::Srgb),
+            "srgb-linear" => Some(ColorSpace::SrgbLinear),
+            "oklab" => Some(ColorSpace::Oklab),
+            "oklch" => Some(ColorSpace::Oklch),
+            "lab" => Some(ColorSpace::Lab),
+            "lch" => Some(ColorSpace::Lch),
+            _ => None,
+        }
+    }
+}
+
+/// interpolate 2 colors in the color space the same way `color-mix()` does
+fn mix_in(color_space: ColorSpace, first: Color, second: Color, weight: f64) -> Color {
+    let lerp = |a: f64, b: f64| a + (b - a) * weight;
+    let lerp3 =
+        |a: (f64, f64, f64), b: (f64, f64, f64)| (lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2));
+    // interpolate the hue using the shorter arc, an achromatic color takes the hue of the other
+    let lerp_polar = |a: (f64, f64, f64), b: (f64, f64, f64), achromatic: f64| {
+        let (mut h1, mut h2) = (a.2, b.2);
+        if a.1 < achromatic {
+            h1 = h2;
+        } else if b.1 < achromatic {
+            h2 = h1;
+        }
+        let mut delta = h2 - h1;
+        if delta > 180.0 {
+            delta -= 360.0;
+        } else if delta < -180.0 {
+            delta += 360.0;
+        }
+        (lerp(a.0, b.0), lerp(a.1, b.1), h1 + delta * weight)
+    };
+    let alpha = lerp(first.alpha(), second.alpha());
+    let (r, g, b) = match color_space {
+        ColorSpace::Srgb => lerp3(first.to_srgb(), second.to_srgb()),
+        ColorSpace::SrgbLinear => {
+            let linear = |(r, g, b): (f64, f64, f64)| {
+                (
+                    space::srgb_to_linear(r),
+                    space::srgb_to_linear(g),
+                    space::srgb_to_linear(b),
+                )
+            };
+            let (r, g, b) = lerp3(linear(first.to_srgb()), linear(second.to_srgb()));
+            (
+                space::linear_to_srgb(r),
+                space::linear_to_srgb(g),
+                space::linear_to_srgb(b),
+            )
+        }
+        ColorSpace::Oklab => space::oklab_to_srgb(lerp3(
+            space::srgb_to_oklab(first.to_srgb()),
+            space::srgb_to_oklab(second.to_srgb()),
+        )),
+        ColorSpace::Oklch => space::gamut_map(
+            lerp_polar(
+                space::to_polar(space::srgb_to_oklab(first.to_srgb())),
+                space::to_polar(space::srgb_to_oklab(second.to_srgb())),
+                1e-4,
+            ),
+            space::oklab_to_srgb,
+        ),
+        ColorSpace::Lab => space::lab_to_srgb(lerp3(
+            space::srgb_to_lab(first.to_srgb()),
+            space::srgb_to_lab(second.to_srgb()),
+        )),
+        ColorSpace::Lch => space::gamut_map(
+            lerp_polar(
+                space::to_polar(space::srgb_to_lab(first.to_srgb())),
+                space::to_polar(space::srgb_to_lab(second.to_srgb())),
+                1e-2,
+            ),
+            space::lab_to_srgb,
+        ),
+    };
+    Color::from_srgb(r, g, b, alpha)
+}
+
+/// whether the value is written in one of the modern color notations which
+/// older browsers doesn't understand
+pub(crate) fn is_modern(value: &str) -> bool {
+    let value = value.trim_start().to_ascii_lowercase();
+    ["oklch(", "oklab(", "lab(", "lch(", "color-mix("]
+        .iter()
+        .any(|function| value.starts_with(function))
+}
+
+/// parse the modern color notations into their closest sRGB color
+pub(crate) fn parse(input: &str) -> Option<Color> {
+    if let Some(args) = input
+        .strip_prefix("color-mix(")
+        .and_then(|rest| rest.trim_end().strip_suffix(')'))
+    {
+        return parse_color_mix(args);
+    }
+    let (name, args) = function_args(input)?;
+    if args.len() != 3 && args.len() != 4 {
+        return None;
+    }
+    // the components can be a percentage of a reference range, or `none` which means 0
+    let component = |i: usize, full: f64| -> Option<f64> {
+        let arg = args[i];
+        if arg == "none" {
+            Some(0.0)
+        } else if let Some(percent) = arg.strip_suffix('%') {
+            parse_number(percent).map(|v| v / 100.0 * full)
+        } else {
+            parse_number(arg)
+        }
+    };
+    let hue = |i: usize| -> Option<f64> {
+        if args[i] == "none" {
+            Some(0.0)
+        } else {
+            parse_hue(args[i])
+        }
+    };
+    let color = match name {
+        "oklch" => ModernColor::oklch(component(0, 1.0)?, component(1, 0.4)?, hue(2)?),
+        "oklab" => ModernColor::oklab(component(0, 1.0)?, component(1, 0.4)?, component(2, 0.4)?),
+        "lab" => ModernColor::lab(
+            component(0, 100.0)?,
+            component(1, 125.0)?,
+            component(2, 125.0)?,
+        ),
+        "lch" => ModernColor::lch(component(0, 100.0)?, component(1, 150.0)?, hue(2)?),
+        _ => return None,
+    };
+    let alpha = match args.get(3) {
+        Some(arg) => parse_alpha(arg)?,
+        None => 1.0,
+    };
+    Some(color.fallback().with_alpha(alpha))
+}
+
+/// parse the arguments of `color-mix()`, ie: `in oklch, red 40%, blue`
+fn parse_color_mix(args: &str) -> Option<Color> {
+    let parts = split_top_level(args, ',');
+    let [space, first, second] = parts.as_slice() else {
+        return None;
+    };
+    let color_space = ColorSpace::from_name(space.trim().strip_prefix("in ")?.trim())?;
+    let (first, p1) = parse_mix_operand(first)?;
+    let (second, p2) = parse_mix_operand(second)?;
+    let weight = match (p1, p2) {
+        (None, None) => 0.5,
+        (Some(p1), None) => 1.0 - p1,
+        (None, Some(p2)) => p2,
+        (Some(p1), Some(p2)) if p1 + p2 > 0.0 => p2 / (p1 + p2),
+        _ => return None,
+    };
+    Some(mix_in(color_space, first, second, weight))
+}
+
+/// parse a color with an optional trailing percentage
+fn parse_mix_operand(operand: &str) -> Option<(Color, Option<f64>)> {
+    let operand = operand.trim();
+    if let Some((color, percent)) = operand.rsplit_once(char::is_whitespace) {
+        if let Some(percent) = percent.strip_suffix('%') {
+            if let Some(percent) = parse_number(percent) {
+                return Some((color.parse().ok()?, Some(percent / 100.0)));
+            }
+        }
+    }
+    Some((operand.parse().ok()?, None))
+}
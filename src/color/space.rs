@@ -0,0 +1,240 @@
+//! conversions in between sRGB and the perceptual color spaces
+//! [Reference](https://bottosson.github.io/posts/oklab/)
+
+/// the 3 components of a color
+pub(crate) type Components = (f64, f64, f64);
+
+/// convert a gamma encoded sRGB channel into linear light
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// convert a linear light channel into gamma encoded sRGB
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// convert gamma encoded sRGB into oklab
+pub(crate) fn srgb_to_oklab((r, g, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+}
+
+/// convert oklab into gamma encoded sRGB, the result may be out of the sRGB gamut
+pub(crate) fn oklab_to_srgb((l, a, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    (
+        linear_to_srgb(4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_),
+        linear_to_srgb(-1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_),
+        linear_to_srgb(-0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_),
+    )
+}
+
+/// convert rectangular a/b coordinates into polar chroma/hue, the hue is in degrees.
+/// This is the conversion of oklab into oklch and of lab into lch
+pub(crate) fn to_polar((l, a, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let chroma = (a * a + b * b).sqrt();
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    (l, chroma, hue)
+}
+
+/// convert polar chroma/hue with the hue in degrees into rectangular a/b coordinates.
+/// This is the conversion of oklch into oklab and of lch into lab
+pub(crate) fn to_rectangular((l, chroma, hue): (f64, f64, f64)) -> (f64, f64, f64) {
+    let hue = hue.to_radians();
+    (l, chroma * hue.cos(), chroma * hue.sin())
+}
+
+/// the D50 reference white used by the css `lab()` and `lch()`
+const D50_WHITE: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+fn multiply(m: [[f64; 3]; 3], (x, y, z): (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        m[0][0] * x + m[0][1] * y + m[0][2] * z,
+        m[1][0] * x + m[1][1] * y + m[1][2] * z,
+        m[2][0] * x + m[2][1] * y + m[2][2] * z,
+    )
+}
+
+/// convert gamma encoded sRGB into CIE lab with the D50 white point
+/// [Reference](https://www.w3.org/TR/css-color-4/#color-conversion-code)
+pub(crate) fn srgb_to_lab((r, g, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let linear = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+    let xyz_d65 = multiply(
+        [
+            [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+            [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+            [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+        ],
+        linear,
+    );
+    let (x, y, z) = multiply(
+        [
+            [
+                1.0479298208405488,
+                0.022946793341019088,
+                -0.05019222954313557,
+            ],
+            [
+                0.029627815688159344,
+                0.990434484573249,
+                -0.01707382502938514,
+            ],
+            [
+                -0.009243058152591178,
+                0.015055144896577895,
+                0.7518742899580008,
+            ],
+        ],
+        xyz_d65,
+    );
+    let f = |v: f64| {
+        if v > EPSILON {
+            v.cbrt()
+        } else {
+            (KAPPA * v + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (
+        f(x / D50_WHITE[0]),
+        f(y / D50_WHITE[1]),
+        f(z / D50_WHITE[2]),
+    );
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// convert CIE lab with the D50 white point into gamma encoded sRGB,
+/// the result may be out of the sRGB gamut
+pub(crate) fn lab_to_srgb((l, a, b): (f64, f64, f64)) -> (f64, f64, f64) {
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let f_inv = |f: f64| {
+        if f.powi(3) > EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let y = if l > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        l / KAPPA
+    };
+    let xyz_d50 = (
+        f_inv(fx) * D50_WHITE[0],
+        y * D50_WHITE[1],
+        f_inv(fz) * D50_WHITE[2],
+    );
+    let xyz_d65 = multiply(
+        [
+            [
+                0.9554734527042182,
+                -0.023098536874261423,
+                0.0632593086610217,
+            ],
+            [
+                -0.028369706963208136,
+                1.0099954580058226,
+                0.021041398966943008,
+            ],
+            [
+                0.012314001688319899,
+                -0.020507696433477912,
+                1.3303659366080753,
+            ],
+        ],
+        xyz_d50,
+    );
+    let (r, g, b) = multiply(
+        [
+            [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+            [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+            [
+                0.05563007969699366,
+                -0.20397695888897652,
+                1.0569715142428786,
+            ],
+        ],
+        xyz_d65,
+    );
+    (linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// convert a polar color (oklch or lch) into sRGB,
+/// reducing the chroma until the color fits in the sRGB gamut
+pub(crate) fn gamut_map(
+    (l, chroma, hue): Components,
+    to_srgb: fn(Components) -> Components,
+) -> Components {
+    let in_gamut = |rgb: (f64, f64, f64)| {
+        const EPSILON: f64 = 1e-6;
+        [rgb.0, rgb.1, rgb.2]
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+    };
+    let rgb = to_srgb(to_rectangular((l, chroma, hue)));
+    if in_gamut(rgb) {
+        return rgb;
+    }
+    let (mut low, mut high) = (0.0, chroma);
+    for _ in 0..24 {
+        let mid = (low + high) / 2.0;
+        if in_gamut(to_srgb(to_rectangular((l, mid, hue)))) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    to_srgb(to_rectangular((l, low, hue)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklab_round_trip() {
+        let rgb = (0.2, 0.4, 0.6);
+        let (r, g, b) = oklab_to_srgb(srgb_to_oklab(rgb));
+        assert!((r - rgb.0).abs() < 1e-6);
+        assert!((g - rgb.1).abs() < 1e-6);
+        assert!((b - rgb.2).abs() < 1e-6);
+
+        let (l, a, b) = srgb_to_oklab((1.0, 1.0, 1.0));
+        assert!((l - 1.0).abs() < 1e-4);
+        assert!(a.abs() < 1e-4 && b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn lab_round_trip() {
+        let rgb = (0.2, 0.4, 0.6);
+        let (r, g, b) = lab_to_srgb(srgb_to_lab(rgb));
+        assert!((r - rgb.0).abs() < 1e-6);
+        assert!((g - rgb.1).abs() < 1e-6);
+        assert!((b - rgb.2).abs() < 1e-6);
+
+        let (l, a, b) = srgb_to_lab((1.0, 1.0, 1.0));
+        assert!((l - 100.0).abs() < 1e-4);
+        assert!(a.abs() < 1e-3 && b.abs() < 1e-3);
+    }
+}
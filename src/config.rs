@@ -0,0 +1,106 @@
+//! provides the configuration used when rendering the generated css
+use crate::formatter::Formatter;
+use crate::lint::{Lint, Warning};
+use crate::media::Breakpoints;
+use std::cell::RefCell;
+use std::ops::Deref;
+
+/// Controls what is written when the css is rendered,
+/// the layout of the css is controlled by its [`Formatter`].
+///
+/// Use [`Config::compact`] for the output of `jss!` and [`Config::pretty`] for the output of `jss_pretty!`.
+/// Other configurations can be derived from those using struct update syntax.
+/// ```rust
+/// use jss::{Config, Formatter};
+///
+/// let config = Config {
+///     color_fallback: true,
+///     ..Config::from(Formatter::pretty())
+/// };
+/// let css = jss::process_css(None, &jss::json::object! {
+///     ".layer": { color: "oklch(0.7 0.1 200)" }
+/// }, &config);
+/// assert_eq!(
+///     "\n.layer {\n    color: rgb(64, 177, 183);\n    color: oklch(0.7 0.1 200);\n}\n",
+///     css
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// the layout of the css
+    pub formatter: Formatter,
+    /// emit an sRGB fallback declaration before the values which are written in
+    /// `oklch()`, `oklab()`, `lab()`, `lch()` or `color-mix()`, for older browsers
+    pub color_fallback: bool,
+    /// write the declarations of the modern properties such as `backdrop-filter` and the rules
+    /// with a `:has()` selector in a `@supports` block, so the older browsers keep the fallback
+    pub supports_fallback: bool,
+    /// the named media queries which can be used as an at-rule, ie: `"@md"`
+    pub breakpoints: Breakpoints,
+    /// the checks to run while rendering
+    pub lint: Lint,
+}
+
+impl Config {
+    /// the compact layout, this is what `jss!` uses
+    pub fn compact() -> Self {
+        Self::from(Formatter::compact())
+    }
+
+    /// the pretty layout, this is what `jss_pretty!` uses
+    pub fn pretty() -> Self {
+        Self::from(Formatter::pretty())
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::compact()
+    }
+}
+
+/// the layout of the formatter with the default breakpoints, no fallback and no lint
+impl From<Formatter> for Config {
+    fn from(formatter: Formatter) -> Self {
+        Self {
+            formatter,
+            color_fallback: false,
+            supports_fallback: false,
+            breakpoints: Breakpoints::default(),
+            lint: Lint::default(),
+        }
+    }
+}
+
+/// the config of a single render together with the warnings found by its lint
+pub(crate) struct Render<'a> {
+    config: &'a Config,
+    warnings: RefCell<Vec<Warning>>,
+}
+
+impl<'a> Render<'a> {
+    pub(crate) fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            warnings: RefCell::new(vec![]),
+        }
+    }
+
+    /// keep the warnings found by a check of the lint
+    pub(crate) fn warn(&self, warnings: impl IntoIterator<Item = Warning>) {
+        self.warnings.borrow_mut().extend(warnings);
+    }
+
+    /// the warnings in the order they were found
+    pub(crate) fn into_warnings(self) -> Vec<Warning> {
+        self.warnings.into_inner()
+    }
+}
+
+impl Deref for Render<'_> {
+    type Target = Config;
+
+    fn deref(&self) -> &Config {
+        self.config
+    }
+}
@@ -0,0 +1,211 @@
+//! a builder for the `@container` at-rule, the size queries of a query container
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::container::inline_size;
+//!
+//! let wide = ContainerQuery::named("sidebar", inline_size().at_least(px(400)));
+//!
+//! let css = jss::jss_ns!("nav",
+//!     ".": {
+//!         container_type: ContainerType::InlineSize,
+//!         container_name: "sidebar",
+//!     },
+//!     ".item": {
+//!         padding: cqi(2),
+//!         [&wide.selector()]: {
+//!             display: "flex",
+//!         },
+//!     },
+//! );
+//! assert_eq!(
+//!     ".nav{container-type:inline-size;container-name:nav__sidebar;}\
+//!      .nav__item{padding:2cqi;@container nav__sidebar (inline-size >= 400px){display:flex;}}",
+//!     css
+//! );
+//! ```
+//!
+//! Inside `jss_ns!` the container names of `container-name`, `container` and `@container`
+//! are namespaced the same way as the classes,
+//! so that components don't respond to the containers of the other components.
+
+use crate::class_namespaced;
+use crate::media::{MediaCondition, RangeFeature};
+use crate::units::LengthUnit;
+use crate::value::{impl_css_value, impl_str_eq};
+use std::fmt;
+
+/// The kind of containment of a query container, the value of `container-type`
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/container-type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerType {
+    /// `normal`, the element is only a container for style queries
+    Normal,
+    /// `size`, query the inline and the block size of the container
+    Size,
+    /// `inline-size`, query the inline size of the container
+    InlineSize,
+}
+
+impl fmt::Display for ContainerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContainerType::Normal => write!(f, "normal"),
+            ContainerType::Size => write!(f, "size"),
+            ContainerType::InlineSize => write!(f, "inline-size"),
+        }
+    }
+}
+
+impl_css_value!(ContainerType);
+
+/// the inline size of the container, the width in a horizontal writing mode
+pub fn inline_size() -> RangeFeature<LengthUnit> {
+    RangeFeature::new("inline-size")
+}
+
+/// the block size of the container, the height in a horizontal writing mode
+pub fn block_size() -> RangeFeature<LengthUnit> {
+    RangeFeature::new("block-size")
+}
+
+/// the orientation of the container
+pub use crate::media::orientation;
+
+/// a style query on the computed value of a custom property of the container,
+/// ie: `style_query("--theme", "dark")` is `style(--theme: dark)`
+pub fn style_query(property: &str, value: impl fmt::Display) -> MediaCondition {
+    MediaCondition::Feature(format!("style({}: {})", property, value))
+}
+
+/// A query on the nearest ancestor container, or the nearest one with the name.
+///
+/// The size features are the same as the media features,
+/// ie: `jss::media::width()` and `jss::media::height()`, together with [`inline_size`] and [`block_size`]
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@container)
+/// ```rust
+/// use jss::prelude::*;
+/// use jss::container::{block_size, inline_size, style_query};
+///
+/// let query = ContainerQuery::new(inline_size().greater_than(px(300)))
+///     .and(!block_size().at_most(px(200)))
+///     .or(style_query("--theme", "dark"));
+/// assert_eq!(
+///     "@container ((inline-size > 300px) and (not (block-size <= 200px))) or style(--theme: dark)",
+///     query.selector()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerQuery {
+    name: Option<String>,
+    condition: MediaCondition,
+}
+
+impl ContainerQuery {
+    /// a query on the nearest ancestor container
+    pub fn new(condition: MediaCondition) -> Self {
+        Self {
+            name: None,
+            condition,
+        }
+    }
+
+    /// a query on the nearest ancestor container with the `container-name`,
+    /// `@container` always needs a condition even when the container is named
+    pub fn named(name: impl ToString, condition: MediaCondition) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            condition,
+        }
+    }
+
+    /// the container also needs to match the condition
+    pub fn and(self, condition: MediaCondition) -> Self {
+        Self {
+            condition: self.condition.and(condition),
+            ..self
+        }
+    }
+
+    /// the container needs to match either the existing condition or this one
+    pub fn or(self, condition: MediaCondition) -> Self {
+        Self {
+            condition: self.condition.or(condition),
+            ..self
+        }
+    }
+
+    /// the at-rule selector, `@container <name> <condition>`
+    pub fn selector(&self) -> String {
+        format!("@container {}", self)
+    }
+}
+
+impl fmt::Display for ContainerQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} {}", name, self.condition),
+            None => write!(f, "{}", self.condition),
+        }
+    }
+}
+
+impl_str_eq!(ContainerQuery);
+
+const CONTAINER: &str = "@container ";
+
+/// namespace the container name of a `@container` selector
+pub(crate) fn selector_namespaced(namespace: &str, selector: &str) -> Option<String> {
+    let query = selector.trim().strip_prefix(CONTAINER)?.trim_start();
+    let (name, condition) = query.split_once(' ').unwrap_or((query, ""));
+    if !is_container_name(name) {
+        return None;
+    }
+    let name = class_namespaced(namespace, name);
+    if condition.is_empty() {
+        Some(format!("{}{}", CONTAINER, name))
+    } else {
+        Some(format!("{}{} {}", CONTAINER, name, condition))
+    }
+}
+
+/// namespace the names in the value of `container-name` or the `container` shorthand,
+/// ie: `sidebar / inline-size`
+pub(crate) fn value_namespaced(namespace: &str, value: &str) -> String {
+    let (names, container_type) = match value.split_once('/') {
+        Some((names, container_type)) => (names, Some(container_type)),
+        None => (value, None),
+    };
+    let names = names
+        .split_whitespace()
+        .map(|name| {
+            if is_container_name(name) {
+                class_namespaced(namespace, name)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    match container_type {
+        Some(container_type) => format!("{} / {}", names, container_type.trim()),
+        None => names,
+    }
+}
+
+/// the keywords and functions such as `var()` are not container names
+fn is_container_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains('(')
+        && !matches!(
+            name,
+            "none"
+                | "and"
+                | "not"
+                | "or"
+                | "inherit"
+                | "initial"
+                | "unset"
+                | "revert"
+                | "revert-layer"
+        )
+}
@@ -0,0 +1,504 @@
+//! the easing functions of transitions and animations
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! assert_eq!("cubic-bezier(0.65, 0, 0.35, 1)", Easing::EASE_IN_OUT_CUBIC.to_string());
+//! assert_eq!("steps(4, jump-end)", steps(4, StepPosition::JumpEnd).unwrap().to_string());
+//! assert_eq!(0.5, Easing::EASE_IN_OUT_CUBIC.eval(0.5));
+//! ```
+
+use crate::units;
+use crate::value::impl_css_value;
+use std::fmt;
+
+/// An easing function, the `<easing-function>` of `transition-timing-function`
+/// and `animation-timing-function`.
+///
+/// The functions are built with [`cubic_bezier`], [`steps`] and [`linear`] which checks
+/// their values, so an `Easing` is always a valid css value
+/// ```rust,compile_fail
+/// let easing = jss::easing::Easing::CubicBezier(2.0, 0.0, 1.0, 1.0);
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Easing {
+    /// `linear`
+    Linear,
+    /// `ease`
+    Ease,
+    /// `ease-in`
+    EaseIn,
+    /// `ease-out`
+    EaseOut,
+    /// `ease-in-out`
+    EaseInOut,
+    /// `step-start`, the same as `steps(1, jump-start)`
+    StepStart,
+    /// `step-end`, the same as `steps(1, jump-end)`
+    StepEnd,
+    /// `cubic-bezier(x1, y1, x2, y2)`, the x values are in the range of 0.0..=1.0
+    #[non_exhaustive]
+    CubicBezier(f64, f64, f64, f64),
+    /// `steps(n, position)`
+    #[non_exhaustive]
+    Steps(u32, StepPosition),
+    /// `linear(...)`, the piecewise linear function in between the stops
+    #[non_exhaustive]
+    Piecewise(Vec<LinearStop>),
+}
+
+/// When the jumps of `steps()` happens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// `jump-start`, the first jump happens when the animation begins
+    JumpStart,
+    /// `jump-end`, the last jump happens when the animation ends
+    JumpEnd,
+    /// `jump-none`, there is no jump at the beginning and the end
+    JumpNone,
+    /// `jump-both`, there are jumps both at the beginning and the end
+    JumpBoth,
+}
+
+impl fmt::Display for StepPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StepPosition::JumpStart => write!(f, "jump-start"),
+            StepPosition::JumpEnd => write!(f, "jump-end"),
+            StepPosition::JumpNone => write!(f, "jump-none"),
+            StepPosition::JumpBoth => write!(f, "jump-both"),
+        }
+    }
+}
+
+/// A stop of the `linear()` easing function, the output value at the input progress.
+/// The input is in the range of 0.0..=1.0 and rendered as a percentage,
+/// when it is omitted the stop is placed evenly in between its neighbours
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearStop {
+    /// the output progress
+    pub output: f64,
+    /// the input progress
+    pub input: Option<f64>,
+}
+
+impl From<f64> for LinearStop {
+    fn from(output: f64) -> Self {
+        Self {
+            output,
+            input: None,
+        }
+    }
+}
+
+/// `(output, input)`
+impl From<(f64, f64)> for LinearStop {
+    fn from((output, input): (f64, f64)) -> Self {
+        Self {
+            output,
+            input: Some(input),
+        }
+    }
+}
+
+/// The error when the easing function would be an invalid css value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EasingError {
+    /// the x values of `cubic-bezier()` must be in the range of 0.0..=1.0
+    XOutOfRange(f64),
+    /// `steps()` needs at least 1 step, or 2 steps for `jump-none`
+    TooFewSteps(u32),
+    /// `linear()` needs at least 2 stops
+    TooFewStops,
+    /// a value is NaN or infinity
+    NotFinite,
+}
+
+impl fmt::Display for EasingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EasingError::XOutOfRange(x) => {
+                write!(
+                    f,
+                    "cubic-bezier x value {} is not in the range of 0 to 1",
+                    x
+                )
+            }
+            EasingError::TooFewSteps(n) => write!(f, "{} is too few steps", n),
+            EasingError::TooFewStops => write!(f, "linear() needs at least 2 stops"),
+            EasingError::NotFinite => write!(f, "the easing has a NaN or infinite value"),
+        }
+    }
+}
+
+impl std::error::Error for EasingError {}
+
+macro_rules! named_easings {
+    ($($(#[$attr:meta])* $name:ident => ($x1:expr, $y1:expr, $x2:expr, $y2:expr);)*) => {
+        impl Easing {
+            $(
+                $(#[$attr])*
+                pub const $name: Easing = Easing::CubicBezier($x1, $y1, $x2, $y2);
+            )*
+        }
+    };
+}
+
+// [Reference](https://easings.net)
+named_easings! {
+    /// easeInSine
+    EASE_IN_SINE => (0.12, 0.0, 0.39, 0.0);
+    /// easeOutSine
+    EASE_OUT_SINE => (0.61, 1.0, 0.88, 1.0);
+    /// easeInOutSine
+    EASE_IN_OUT_SINE => (0.37, 0.0, 0.63, 1.0);
+    /// easeInQuad
+    EASE_IN_QUAD => (0.11, 0.0, 0.5, 0.0);
+    /// easeOutQuad
+    EASE_OUT_QUAD => (0.5, 1.0, 0.89, 1.0);
+    /// easeInOutQuad
+    EASE_IN_OUT_QUAD => (0.45, 0.0, 0.55, 1.0);
+    /// easeInCubic
+    EASE_IN_CUBIC => (0.32, 0.0, 0.67, 0.0);
+    /// easeOutCubic
+    EASE_OUT_CUBIC => (0.33, 1.0, 0.68, 1.0);
+    /// easeInOutCubic
+    EASE_IN_OUT_CUBIC => (0.65, 0.0, 0.35, 1.0);
+    /// easeInQuart
+    EASE_IN_QUART => (0.5, 0.0, 0.75, 0.0);
+    /// easeOutQuart
+    EASE_OUT_QUART => (0.25, 1.0, 0.5, 1.0);
+    /// easeInOutQuart
+    EASE_IN_OUT_QUART => (0.76, 0.0, 0.24, 1.0);
+    /// easeInQuint
+    EASE_IN_QUINT => (0.64, 0.0, 0.78, 0.0);
+    /// easeOutQuint
+    EASE_OUT_QUINT => (0.22, 1.0, 0.36, 1.0);
+    /// easeInOutQuint
+    EASE_IN_OUT_QUINT => (0.83, 0.0, 0.17, 1.0);
+    /// easeInExpo
+    EASE_IN_EXPO => (0.7, 0.0, 0.84, 0.0);
+    /// easeOutExpo
+    EASE_OUT_EXPO => (0.16, 1.0, 0.3, 1.0);
+    /// easeInOutExpo
+    EASE_IN_OUT_EXPO => (0.87, 0.0, 0.13, 1.0);
+    /// easeInCirc
+    EASE_IN_CIRC => (0.55, 0.0, 1.0, 0.45);
+    /// easeOutCirc
+    EASE_OUT_CIRC => (0.0, 0.55, 0.45, 1.0);
+    /// easeInOutCirc
+    EASE_IN_OUT_CIRC => (0.85, 0.0, 0.15, 1.0);
+    /// easeInBack
+    EASE_IN_BACK => (0.36, 0.0, 0.66, -0.56);
+    /// easeOutBack
+    EASE_OUT_BACK => (0.34, 1.56, 0.64, 1.0);
+    /// easeInOutBack
+    EASE_IN_OUT_BACK => (0.68, -0.6, 0.32, 1.6);
+}
+
+impl Easing {
+    /// a `cubic-bezier()` with the x values checked to be in the range of 0.0..=1.0
+    pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Result<Self, EasingError> {
+        let easing = Easing::CubicBezier(x1, y1, x2, y2);
+        easing.validate()?;
+        Ok(easing)
+    }
+
+    /// a `steps()` with at least 1 step, or 2 steps for `jump-none`
+    pub fn steps(n: u32, position: StepPosition) -> Result<Self, EasingError> {
+        let easing = Easing::Steps(n, position);
+        easing.validate()?;
+        Ok(easing)
+    }
+
+    /// a `linear()` with at least 2 stops
+    pub fn linear(
+        stops: impl IntoIterator<Item = impl Into<LinearStop>>,
+    ) -> Result<Self, EasingError> {
+        let easing = Easing::Piecewise(stops.into_iter().map(Into::into).collect());
+        easing.validate()?;
+        Ok(easing)
+    }
+
+    /// check the values of the easing function
+    fn validate(&self) -> Result<(), EasingError> {
+        let check = |values: &[f64]| {
+            if values.iter().all(|v| v.is_finite()) {
+                Ok(())
+            } else {
+                Err(EasingError::NotFinite)
+            }
+        };
+        match self {
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                check(&[*x1, *y1, *x2, *y2])?;
+                match [x1, x2].into_iter().find(|x| !(0.0..=1.0).contains(*x)) {
+                    Some(x) => Err(EasingError::XOutOfRange(*x)),
+                    None => Ok(()),
+                }
+            }
+            Easing::Steps(n, position) => {
+                let min = if *position == StepPosition::JumpNone {
+                    2
+                } else {
+                    1
+                };
+                if *n < min {
+                    Err(EasingError::TooFewSteps(*n))
+                } else {
+                    Ok(())
+                }
+            }
+            Easing::Piecewise(stops) => {
+                if stops.len() < 2 {
+                    return Err(EasingError::TooFewStops);
+                }
+                let values: Vec<f64> = stops
+                    .iter()
+                    .flat_map(|stop| [Some(stop.output), stop.input])
+                    .flatten()
+                    .collect();
+                check(&values)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// the output progress of the easing function at the input progress `t`,
+    /// which is typically in the range of 0.0..=1.0
+    /// ```rust
+    /// use jss::prelude::*;
+    ///
+    /// assert_eq!(0.25, Easing::Linear.eval(0.25));
+    /// assert_eq!(0.5, steps(2, StepPosition::JumpEnd).unwrap().eval(0.75));
+    /// assert!(Easing::EaseIn.eval(0.25) < 0.25);
+    /// ```
+    pub fn eval(&self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier_at(0.25, 0.1, 0.25, 1.0, t),
+            Easing::EaseIn => cubic_bezier_at(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier_at(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier_at(0.42, 0.0, 0.58, 1.0, t),
+            Easing::StepStart => steps_at(1, StepPosition::JumpStart, t),
+            Easing::StepEnd => steps_at(1, StepPosition::JumpEnd, t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier_at(*x1, *y1, *x2, *y2, t),
+            Easing::Steps(n, position) => steps_at(*n, *position, t),
+            Easing::Piecewise(stops) => linear_at(stops, t),
+        }
+    }
+}
+
+/// solve the x of the curve for t then return its y
+fn cubic_bezier_at(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    // the polynomial coefficients of the curve which starts at 0,0 and ends at 1,1
+    let coefficients = |p1: f64, p2: f64| {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+        (a, b, c)
+    };
+    let (ax, bx, cx) = coefficients(x1, x2);
+    let (ay, by, cy) = coefficients(y1, y2);
+    let sample = |(a, b, c): (f64, f64, f64), s: f64| ((a * s + b) * s + c) * s;
+
+    if t <= 0.0 || t >= 1.0 {
+        // extrapolate with the tangent at the end points
+        let (x, y) = if t <= 0.0 { (x1, y1) } else { (x2, y2) };
+        let end = if t <= 0.0 { 0.0 } else { 1.0 };
+        let slope = if t <= 0.0 {
+            if x > 0.0 {
+                y / x
+            } else {
+                0.0
+            }
+        } else if x < 1.0 {
+            (y - 1.0) / (x - 1.0)
+        } else {
+            0.0
+        };
+        return end + slope * (t - end);
+    }
+
+    // newton's method, then bisection when it doesn't converge
+    let mut s = t;
+    for _ in 0..8 {
+        let x = sample((ax, bx, cx), s) - t;
+        if x.abs() < 1e-9 {
+            return sample((ay, by, cy), s);
+        }
+        let derivative = (3.0 * ax * s + 2.0 * bx) * s + cx;
+        if derivative.abs() < 1e-9 {
+            break;
+        }
+        s -= x / derivative;
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    s = t;
+    for _ in 0..64 {
+        let x = sample((ax, bx, cx), s);
+        if (x - t).abs() < 1e-9 {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    sample((ay, by, cy), s)
+}
+
+/// [Reference](https://www.w3.org/TR/css-easing-1/#step-easing-algo)
+fn steps_at(n: u32, position: StepPosition, t: f64) -> f64 {
+    let n = n.max(1) as f64;
+    let mut step = (t * n).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => n,
+        StepPosition::JumpNone => (n - 1.0).max(1.0),
+        StepPosition::JumpBoth => n + 1.0,
+    };
+    if t >= 0.0 && step < 0.0 {
+        step = 0.0;
+    }
+    if t <= 1.0 && step > jumps {
+        step = jumps;
+    }
+    step / jumps
+}
+
+/// [Reference](https://www.w3.org/TR/css-easing-2/#linear-easing-function-output)
+fn linear_at(stops: &[LinearStop], t: f64) -> f64 {
+    let points = linear_points(stops);
+    match points.as_slice() {
+        [] => t,
+        [(_, output)] => *output,
+        _ => {
+            // the segment containing t, the first or last segment is extrapolated
+            let index = points
+                .windows(2)
+                .position(|pair| t < pair[1].0)
+                .unwrap_or(points.len() - 2);
+            let ((x0, y0), (x1, y1)) = (points[index], points[index + 1]);
+            if x1 == x0 {
+                y1
+            } else {
+                y0 + (y1 - y0) * (t - x0) / (x1 - x0)
+            }
+        }
+    }
+}
+
+/// the `(input, output)` points of the stops with the missing inputs filled in
+fn linear_points(stops: &[LinearStop]) -> Vec<(f64, f64)> {
+    let mut inputs: Vec<Option<f64>> = stops.iter().map(|stop| stop.input).collect();
+    if let Some(first) = inputs.first_mut() {
+        first.get_or_insert(0.0);
+    }
+    if let Some(last) = inputs.last_mut() {
+        last.get_or_insert(1.0);
+    }
+    // an input can not be less than the inputs before it
+    let mut max = f64::MIN;
+    for input in inputs.iter_mut().flatten() {
+        max = max.max(*input);
+        *input = max;
+    }
+    // the missing inputs are spread evenly in between the known ones
+    let mut i = 0;
+    while i < inputs.len() {
+        if inputs[i].is_none() {
+            let start = i - 1;
+            let end = (i..inputs.len())
+                .find(|j| inputs[*j].is_some())
+                .expect("last input is set");
+            let (from, to) = (inputs[start].unwrap(), inputs[end].unwrap());
+            for (k, input) in inputs.iter_mut().enumerate().take(end).skip(i) {
+                *input = Some(from + (to - from) * (k - start) as f64 / (end - start) as f64);
+            }
+            i = end;
+        }
+        i += 1;
+    }
+    inputs
+        .into_iter()
+        .zip(stops)
+        .map(|(input, stop)| (input.unwrap_or_default(), stop.output))
+        .collect()
+}
+
+impl fmt::Display for LinearStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        units::write_number(f, self.output)?;
+        if let Some(input) = self.input {
+            write!(f, " ")?;
+            units::write_number(f, input * 100.0)?;
+            write!(f, "%")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Easing::Linear => write!(f, "linear"),
+            Easing::Ease => write!(f, "ease"),
+            Easing::EaseIn => write!(f, "ease-in"),
+            Easing::EaseOut => write!(f, "ease-out"),
+            Easing::EaseInOut => write!(f, "ease-in-out"),
+            Easing::StepStart => write!(f, "step-start"),
+            Easing::StepEnd => write!(f, "step-end"),
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                write!(f, "cubic-bezier(")?;
+                units::write_numbers(f, &[*x1, *y1, *x2, *y2])?;
+                write!(f, ")")
+            }
+            Easing::Steps(n, position) => write!(f, "steps({}, {})", n, position),
+            Easing::Piecewise(stops) => {
+                write!(f, "linear(")?;
+                for (i, stop) in stops.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", stop)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl_css_value!(Easing);
+
+/// the `cubic-bezier()` easing function, the x values must be in the range of 0.0..=1.0
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function/cubic-bezier)
+pub fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> Result<Easing, EasingError> {
+    Easing::cubic_bezier(x1, y1, x2, y2)
+}
+
+/// the `steps()` easing function
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function/steps)
+pub fn steps(n: u32, position: StepPosition) -> Result<Easing, EasingError> {
+    Easing::steps(n, position)
+}
+
+/// the `linear()` easing function, the stops are either the output or `(output, input)`
+/// ```rust
+/// use jss::easing::linear;
+/// use jss::prelude::*;
+///
+/// let bounce = linear([(0.0, 0.0), (1.0, 0.5), (0.75, 0.75), (1.0, 1.0)]).unwrap();
+/// assert_eq!("linear(0 0%, 1 50%, 0.75 75%, 1 100%)", bounce);
+/// assert_eq!(0.875, bounce.eval(0.625));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function/linear)
+pub fn linear(
+    stops: impl IntoIterator<Item = impl Into<LinearStop>>,
+) -> Result<Easing, EasingError> {
+    Easing::linear(stops)
+}
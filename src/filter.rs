@@ -0,0 +1,172 @@
+//! the filter functions of `filter` and `backdrop-filter`, composed into a [`Filter`] chain
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! assert_eq!("blur(4px) brightness(1.2) saturate(1.5)", blur(px(4)).brightness(1.2).saturate(1.5));
+//! ```
+
+use crate::shadow::TextShadow;
+use crate::units::{self, Angle, Length};
+use crate::value::impl_css_value;
+use std::fmt;
+
+/// A single css filter function
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function)
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterFunction {
+    /// `blur(radius)`
+    Blur(Length),
+    /// `brightness(amount)`, 1.0 leaves the input unchanged
+    Brightness(f64),
+    /// `contrast(amount)`, 1.0 leaves the input unchanged
+    Contrast(f64),
+    /// `drop-shadow(shadow)`, a shadow without `inset` and the spread radius
+    DropShadow(TextShadow),
+    /// `grayscale(amount)`, 1.0 is completely grayscale
+    Grayscale(f64),
+    /// `hue-rotate(angle)`
+    HueRotate(Angle),
+    /// `invert(amount)`, 1.0 is completely inverted
+    Invert(f64),
+    /// `opacity(amount)`, 0.0 is completely transparent
+    Opacity(f64),
+    /// `saturate(amount)`, 1.0 leaves the input unchanged
+    Saturate(f64),
+    /// `sepia(amount)`, 1.0 is completely sepia
+    Sepia(f64),
+    /// `url(...)`, a reference to an svg filter
+    Url(String),
+}
+
+/// A space separated chain of filter functions, applied from left to right.
+/// An empty chain is written as `none`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Filter(pub Vec<FilterFunction>);
+
+impl Filter {
+    /// an empty chain of filter functions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// append the filter function to the chain
+    pub fn push(mut self, function: FilterFunction) -> Self {
+        self.0.push(function);
+        self
+    }
+}
+
+impl fmt::Display for FilterFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, amount) = match self {
+            FilterFunction::Blur(radius) => return write!(f, "blur({})", radius),
+            FilterFunction::DropShadow(shadow) => return write!(f, "drop-shadow({})", shadow),
+            FilterFunction::HueRotate(angle) => return write!(f, "hue-rotate({})", angle),
+            FilterFunction::Url(url) => return write!(f, "{}", crate::fns::url(url)),
+            FilterFunction::Brightness(amount) => ("brightness", amount),
+            FilterFunction::Contrast(amount) => ("contrast", amount),
+            FilterFunction::Grayscale(amount) => ("grayscale", amount),
+            FilterFunction::Invert(amount) => ("invert", amount),
+            FilterFunction::Opacity(amount) => ("opacity", amount),
+            FilterFunction::Saturate(amount) => ("saturate", amount),
+            FilterFunction::Sepia(amount) => ("sepia", amount),
+        };
+        write!(f, "{}(", name)?;
+        units::write_number(f, *amount)?;
+        write!(f, ")")
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+        for (i, function) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", function)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<FilterFunction> for Filter {
+    fn from(function: FilterFunction) -> Self {
+        Self(vec![function])
+    }
+}
+
+impl_css_value!(Filter, FilterFunction);
+
+/// declare the filter functions as a function which starts a [`Filter`]
+/// and as a method of [`Filter`] which appends to it
+macro_rules! declare_filters {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($arg:ident: $ty:ty) => $variant:ident;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name($arg: $ty) -> Filter {
+                Filter::new().$name($arg)
+            }
+        )*
+
+        impl Filter {
+            $(
+                $(#[$attr])*
+                pub fn $name(self, $arg: $ty) -> Self {
+                    self.push(FilterFunction::$variant($arg))
+                }
+            )*
+        }
+    };
+}
+
+declare_filters! {
+    /// blur the input by the radius
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/blur)
+    blur(radius: Length) => Blur;
+    /// make the input brighter or darker
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/brightness)
+    brightness(amount: f64) => Brightness;
+    /// adjust the contrast of the input
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/contrast)
+    contrast(amount: f64) => Contrast;
+    /// a shadow which follows the shape of the input, ie: the transparent parts of an image.
+    /// It is a [`TextShadow`] since `inset` and the spread radius are not supported
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/drop-shadow)
+    drop_shadow(shadow: TextShadow) => DropShadow;
+    /// convert the input to grayscale
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/grayscale)
+    grayscale(amount: f64) => Grayscale;
+    /// rotate the hue of the input
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/hue-rotate)
+    hue_rotate(angle: Angle) => HueRotate;
+    /// invert the colors of the input
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/invert)
+    invert(amount: f64) => Invert;
+    /// make the input transparent
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/opacity)
+    opacity(amount: f64) => Opacity;
+    /// saturate or desaturate the input
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/saturate)
+    saturate(amount: f64) => Saturate;
+    /// convert the input to sepia
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/filter-function/sepia)
+    sepia(amount: f64) => Sepia;
+}
+
+/// a reference to an svg filter, ie: `filter_url("filters.svg#shadow")`
+pub fn filter_url(url: impl fmt::Display) -> Filter {
+    Filter::new().url(url)
+}
+
+impl Filter {
+    /// a reference to an svg filter, ie: `filter_url("filters.svg#shadow")`
+    pub fn url(self, url: impl fmt::Display) -> Self {
+        self.push(FilterFunction::Url(url.to_string()))
+    }
+}
@@ -1,6 +1,289 @@
-use std::fmt::Display;
+use crate::color::{ColorSpace, ModernColor};
+use crate::units::{Calc, Quantity, Resolution, Unit};
+use crate::value::impl_css_value;
+use std::fmt::{self, Display};
+use std::ops::{Add, Sub};
 
 /// the rgb css function
 pub fn rgb(r: impl Display, g: impl Display, b: impl Display) -> String {
     format!("rgb({r}, {g}, {b})")
 }
+
+/// a css string, wrapped in double quotes with the quotes and backslashes escaped
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!(r#""Fira Sans", serif"#, List::comma([quoted("Fira Sans"), "serif".into()]).to_string());
+/// assert_eq!(r#""a a" "b c""#, List::space([quoted("a a"), quoted("b c")]).to_string());
+/// ```
+pub fn quoted(s: impl Display) -> String {
+    let s = s.to_string();
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            // a newline can not be in a css string as is
+            '\n' => quoted.push_str("\\a "),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// the oklch css function,
+/// lightness in the range of 0.0..=1.0, chroma typically up to 0.4 and the hue in degrees
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/oklch)
+pub fn oklch(lightness: f64, chroma: f64, hue: f64) -> ModernColor {
+    ModernColor::oklch(lightness, chroma, hue)
+}
+
+/// the oklab css function,
+/// lightness in the range of 0.0..=1.0 and the a and b axes typically within -0.4..=0.4
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/oklab)
+pub fn oklab(lightness: f64, a: f64, b: f64) -> ModernColor {
+    ModernColor::oklab(lightness, a, b)
+}
+
+/// the lab css function,
+/// lightness in the range of 0.0..=100.0 and the a and b axes typically within -125.0..=125.0
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/lab)
+pub fn lab(lightness: f64, a: f64, b: f64) -> ModernColor {
+    ModernColor::lab(lightness, a, b)
+}
+
+/// the lch css function,
+/// lightness in the range of 0.0..=100.0, chroma typically up to 150.0 and the hue in degrees
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/lch)
+pub fn lch(lightness: f64, chroma: f64, hue: f64) -> ModernColor {
+    ModernColor::lch(lightness, chroma, hue)
+}
+
+/// the color-mix css function, `weight` (0.0..=1.0) is the proportion of `second`
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/color-mix)
+pub fn color_mix(
+    space: ColorSpace,
+    first: impl Into<ModernColor>,
+    second: impl Into<ModernColor>,
+    weight: f64,
+) -> ModernColor {
+    ModernColor::color_mix(space, first, second, weight)
+}
+
+/// the calc css function, an expression which can mix incompatible units.
+/// The quantities which can be converted into each other are still folded,
+/// adding or subtracting the quantities also starts a `Calc`
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("calc(100% - 20px)", calc(percent(100)) - px(20));
+/// assert_eq!("calc(100% - 20px)", percent(100) - px(10) - px(10));
+/// assert_eq!("14px", calc(px(10)) + px(4));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/calc)
+pub fn calc<U: Unit>(value: impl Into<Calc<U>>) -> Calc<U> {
+    value.into()
+}
+
+/// the min css function, the smaller of the 2 values
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("min(100% - 2rem, 600px)", min(percent(100) - rem(2), px(600)));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/min)
+pub fn min<U: Unit>(a: impl Into<Calc<U>>, b: impl Into<Calc<U>>) -> Calc<U> {
+    Calc::min([a.into(), b.into()])
+}
+
+/// the max css function, the larger of the 2 values
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/max)
+pub fn max<U: Unit>(a: impl Into<Calc<U>>, b: impl Into<Calc<U>>) -> Calc<U> {
+    Calc::max([a.into(), b.into()])
+}
+
+/// the clamp css function, the preferred value limited in between min and max
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("clamp(1rem, 2.5vw + 0.5rem, 2rem)", clamp(rem(1), vw(2.5) + rem(0.5), rem(2)));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/clamp)
+pub fn clamp<U: Unit>(
+    min: impl Into<Calc<U>>,
+    preferred: impl Into<Calc<U>>,
+    max: impl Into<Calc<U>>,
+) -> Calc<U> {
+    Calc::clamp(min.into(), preferred.into(), max.into())
+}
+
+/// the url css function, the url is quoted and escaped
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!(r#"url("img/bg.png")"#, url("img/bg.png"));
+/// assert_eq!(r#"url("say \"hi\".svg")"#, url(r#"say "hi".svg"#));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/url)
+pub fn url(url: impl Display) -> String {
+    format!("url({})", quoted(url))
+}
+
+/// the attr css function, the value of the attribute of the element
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("attr(data-label)", attr("data-label"));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/attr)
+pub fn attr(name: impl Display) -> String {
+    format!("attr({})", name)
+}
+
+/// the attr css function with a fallback when the attribute is missing
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!(r#"attr(data-label, "none")"#, attr_or("data-label", quoted("none")));
+/// ```
+pub fn attr_or(name: impl Display, fallback: impl Display) -> String {
+    format!("attr({}, {})", name, fallback)
+}
+
+/// the image-set css function, the images together with their resolution
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!(
+///     r#"image-set(url("logo.png") 1x, url("logo@2x.png") 2x)"#,
+///     image_set([(url("logo.png"), x(1)), (url("logo@2x.png"), x(2))])
+/// );
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/image/image-set)
+pub fn image_set<I: Display>(images: impl IntoIterator<Item = (I, Resolution)>) -> String {
+    let images: Vec<String> = images
+        .into_iter()
+        .map(|(image, resolution)| format!("{} {}", image, resolution))
+        .collect();
+    format!("image-set({})", images.join(", "))
+}
+
+/// the var css function, the `--` prefix of the custom property is added when omitted.
+/// It can also be used in `calc()`, `min()`, `max()` and `clamp()`
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("var(--gutter)", var("gutter"));
+/// assert_eq!("calc(100% - var(--gutter))", percent(100) - var("--gutter"));
+/// assert_eq!("var(--accent, rgb(255, 0, 0))", var("accent").or(Color::rgb(255, 0, 0)));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/var)
+pub fn var(name: impl Display) -> Var {
+    let name = name.to_string();
+    let name = if name.starts_with("--") {
+        name
+    } else {
+        format!("--{}", name)
+    };
+    Var {
+        name,
+        fallback: None,
+    }
+}
+
+/// the env css function, the value of a variable defined by the user agent
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("env(safe-area-inset-top, 20px)", env("safe-area-inset-top").or(px(20)));
+/// assert_eq!("calc(env(safe-area-inset-top) + 1rem)", env("safe-area-inset-top") + rem(1));
+/// ```
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/env)
+pub fn env(name: impl Display) -> Env {
+    Env {
+        name: name.to_string(),
+        fallback: None,
+    }
+}
+
+/// A reference to a custom property, created with [`var`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Var {
+    name: String,
+    fallback: Option<String>,
+}
+
+/// A reference to a user agent variable, created with [`env`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Env {
+    name: String,
+    fallback: Option<String>,
+}
+
+macro_rules! impl_substitution {
+    ($ty:ident => $function:literal) => {
+        impl $ty {
+            /// the value used when the variable is not defined
+            pub fn or(mut self, fallback: impl Display) -> Self {
+                self.fallback = Some(fallback.to_string());
+                self
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match &self.fallback {
+                    Some(fallback) => write!(f, "{}({}, {})", $function, self.name, fallback),
+                    None => write!(f, "{}({})", $function, self.name),
+                }
+            }
+        }
+
+        impl<U: Unit> From<$ty> for Calc<U> {
+            fn from(v: $ty) -> Self {
+                Calc::substitution(v.to_string())
+            }
+        }
+
+        impl<U: Unit> Add<$ty> for Quantity<U> {
+            type Output = Calc<U>;
+
+            fn add(self, rhs: $ty) -> Calc<U> {
+                Calc::from(self) + rhs
+            }
+        }
+
+        impl<U: Unit> Sub<$ty> for Quantity<U> {
+            type Output = Calc<U>;
+
+            fn sub(self, rhs: $ty) -> Calc<U> {
+                Calc::from(self) - rhs
+            }
+        }
+
+        impl<U: Unit> Add<Quantity<U>> for $ty {
+            type Output = Calc<U>;
+
+            fn add(self, rhs: Quantity<U>) -> Calc<U> {
+                Calc::from(self) + rhs
+            }
+        }
+
+        impl<U: Unit> Sub<Quantity<U>> for $ty {
+            type Output = Calc<U>;
+
+            fn sub(self, rhs: Quantity<U>) -> Calc<U> {
+                Calc::from(self) - rhs
+            }
+        }
+
+        impl_css_value!($ty);
+    };
+}
+
+impl_substitution!(Var => "var");
+impl_substitution!(Env => "env");
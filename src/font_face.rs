@@ -0,0 +1,470 @@
+//! a builder for the `@font-face` at-rule with typed descriptors
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::json::JsonValue;
+//!
+//! let inter = FontFace::new("Inter")
+//!     .src(FontSource::local("Inter"))
+//!     .src(FontSource::url("/fonts/inter.woff2").format(FontFormat::Woff2))
+//!     .weight_range(100, 900)
+//!     .display(FontDisplay::Swap)
+//!     .unicode_range(0x0000, 0x00ff);
+//!
+//! let css = jss::jss!(
+//!     "@font-face": JsonValue::try_from(&inter).unwrap(),
+//!     "body": {
+//!         font_family: inter.family_list(["sans-serif"]),
+//!     },
+//! );
+//! assert_eq!(
+//!     r#"@font-face{font-family:"Inter";src:local("Inter"), url("/fonts/inter.woff2") format("woff2");font-weight:100 900;font-display:swap;unicode-range:U+0-FF;}body{font-family:"Inter", sans-serif;}"#,
+//!     css
+//! );
+//! ```
+
+use crate::fns::{quoted, url};
+use crate::units::Percentage;
+use crate::value::List;
+use std::fmt;
+
+/// The font format hint of a font file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontFormat {
+    /// `woff2`
+    Woff2,
+    /// `woff`
+    Woff,
+    /// `truetype`
+    TrueType,
+    /// `opentype`
+    OpenType,
+    /// `collection`, an OpenType collection
+    Collection,
+    /// `embedded-opentype`
+    EmbeddedOpenType,
+    /// `svg`
+    Svg,
+}
+
+impl fmt::Display for FontFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = match self {
+            FontFormat::Woff2 => "woff2",
+            FontFormat::Woff => "woff",
+            FontFormat::TrueType => "truetype",
+            FontFormat::OpenType => "opentype",
+            FontFormat::Collection => "collection",
+            FontFormat::EmbeddedOpenType => "embedded-opentype",
+            FontFormat::Svg => "svg",
+        };
+        write!(f, "format({})", quoted(format))
+    }
+}
+
+/// An entry of the `src` descriptor, the sources are tried in order
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face/src)
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// a font file, with the format hint so the browser can skip the formats it doesn't support
+    Url {
+        /// the location of the font file
+        url: String,
+        /// the format of the font file
+        format: Option<FontFormat>,
+    },
+    /// the full name or the postscript name of a font installed on the device
+    Local(String),
+}
+
+impl FontSource {
+    /// a font file, ie: `url("/fonts/inter.woff2")`
+    pub fn url(url: impl ToString) -> Self {
+        FontSource::Url {
+            url: url.to_string(),
+            format: None,
+        }
+    }
+
+    /// a font installed on the device, ie: `local("Inter")`
+    pub fn local(name: impl ToString) -> Self {
+        FontSource::Local(name.to_string())
+    }
+
+    /// the format hint of the font file, it has no effect on a local font
+    pub fn format(self, format: FontFormat) -> Self {
+        match self {
+            FontSource::Url { url, .. } => FontSource::Url {
+                url,
+                format: Some(format),
+            },
+            local => local,
+        }
+    }
+}
+
+impl fmt::Display for FontSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontSource::Url { url: src, format } => {
+                write!(f, "{}", url(src))?;
+                if let Some(format) = format {
+                    write!(f, " {}", format)?;
+                }
+                Ok(())
+            }
+            FontSource::Local(name) => write!(f, "local({})", quoted(name)),
+        }
+    }
+}
+
+/// When the font is used while it is still loading, the value of `font-display`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontDisplay {
+    /// `auto`, up to the browser
+    Auto,
+    /// `block`, invisible text for a short period then swaps when loaded
+    Block,
+    /// `swap`, the fallback font is used until it is loaded
+    Swap,
+    /// `fallback`, swaps only if it is loaded within a short period
+    Fallback,
+    /// `optional`, used only if it is available almost immediately
+    Optional,
+}
+
+impl fmt::Display for FontDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontDisplay::Auto => write!(f, "auto"),
+            FontDisplay::Block => write!(f, "block"),
+            FontDisplay::Swap => write!(f, "swap"),
+            FontDisplay::Fallback => write!(f, "fallback"),
+            FontDisplay::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+/// The style of the font file, the value of `font-style`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontStyle {
+    /// `normal`
+    Normal,
+    /// `italic`
+    Italic,
+    /// `oblique`
+    Oblique,
+}
+
+impl fmt::Display for FontStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontStyle::Normal => write!(f, "normal"),
+            FontStyle::Italic => write!(f, "italic"),
+            FontStyle::Oblique => write!(f, "oblique"),
+        }
+    }
+}
+
+/// The error when the font face would be an invalid css at-rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontFaceError {
+    /// there is no `src`
+    MissingSource,
+    /// the font weight is not in the range of 1..=1000
+    WeightOutOfRange(u16),
+    /// the start of a range is after its end
+    UnorderedRange {
+        /// the descriptor of the range, ie: `font-weight`
+        descriptor: &'static str,
+    },
+    /// the code points are not in the range of 0..=0x10FFFF or the start is after the end
+    InvalidUnicodeRange {
+        /// the first code point of the range
+        start: u32,
+        /// the last code point of the range
+        end: u32,
+    },
+}
+
+impl fmt::Display for FontFaceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontFaceError::MissingSource => write!(f, "the font face has no src"),
+            FontFaceError::WeightOutOfRange(weight) => {
+                write!(f, "font weight {} is not in the range of 1 to 1000", weight)
+            }
+            FontFaceError::UnorderedRange { descriptor } => {
+                write!(f, "the start of the {} range is after its end", descriptor)
+            }
+            FontFaceError::InvalidUnicodeRange { start, end } => {
+                write!(f, "invalid unicode range U+{:X}-{:X}", start, end)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FontFaceError {}
+
+/// A font face, rendered as `@font-face`.
+///
+/// Multiple font faces with the same family can be used for the different weights,
+/// styles and unicode ranges of the font,
+/// these are converted into an array of blocks which writes a `@font-face` for each of them.
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face)
+/// ```rust
+/// use jss::prelude::*;
+/// use jss::json::JsonValue;
+///
+/// let face = |weight: u16| {
+///     FontFace::new("Roboto")
+///         .src(FontSource::url(format!("/roboto-{}.woff2", weight)))
+///         .weight(weight)
+/// };
+/// let faces = [face(400), face(700)];
+/// let css = jss::jss!(
+///     "@font-face": FontFace::blocks(&faces).unwrap(),
+/// );
+/// assert_eq!(
+///     r#"@font-face{font-family:"Roboto";src:url("/roboto-400.woff2");font-weight:400;}@font-face{font-family:"Roboto";src:url("/roboto-700.woff2");font-weight:700;}"#,
+///     css
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    family: String,
+    sources: Vec<FontSource>,
+    weight: Option<(u16, u16)>,
+    stretch: Option<(Percentage, Percentage)>,
+    style: Option<FontStyle>,
+    display: Option<FontDisplay>,
+    unicode_ranges: Vec<(u32, u32)>,
+    /// the descriptors which have no constraint, such as `size-adjust`
+    descriptors: Vec<(&'static str, String)>,
+}
+
+impl FontFace {
+    /// a font face of the family, the name used in `font-family`
+    pub fn new(family: impl ToString) -> Self {
+        Self {
+            family: family.to_string(),
+            sources: vec![],
+            weight: None,
+            stretch: None,
+            style: None,
+            display: None,
+            unicode_ranges: vec![],
+            descriptors: vec![],
+        }
+    }
+
+    /// add a source of the font, the sources are tried in the order they are added
+    pub fn src(mut self, source: FontSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// the weight of the font file, in the range of 1..=1000
+    pub fn weight(self, weight: u16) -> Self {
+        self.weight_range(weight, weight)
+    }
+
+    /// the weights supported by a variable font, ie: `100 900`
+    pub fn weight_range(mut self, min: u16, max: u16) -> Self {
+        self.weight = Some((min, max));
+        self
+    }
+
+    /// the width of the font file, ie: `percentage(75)` for a condensed font
+    pub fn stretch(self, stretch: Percentage) -> Self {
+        self.stretch_range(stretch, stretch)
+    }
+
+    /// the widths supported by a variable font, ie: `75% 125%`
+    pub fn stretch_range(mut self, min: Percentage, max: Percentage) -> Self {
+        self.stretch = Some((min, max));
+        self
+    }
+
+    /// the style of the font file
+    pub fn style(mut self, style: FontStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// when the font is used while it is still loading
+    pub fn display(mut self, display: FontDisplay) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    /// add a range of code points which the font file supports, both ends are inclusive
+    pub fn unicode_range(mut self, start: u32, end: u32) -> Self {
+        self.unicode_ranges.push((start, end));
+        self
+    }
+
+    /// the ascent metric of the font as a percentage of the font size, ie: `percentage(90)`
+    pub fn ascent_override(self, ascent: Percentage) -> Self {
+        self.descriptor("ascent-override", ascent)
+    }
+
+    /// the descent metric of the font as a percentage of the font size
+    pub fn descent_override(self, descent: Percentage) -> Self {
+        self.descriptor("descent-override", descent)
+    }
+
+    /// the line gap metric of the font as a percentage of the font size
+    pub fn line_gap_override(self, line_gap: Percentage) -> Self {
+        self.descriptor("line-gap-override", line_gap)
+    }
+
+    /// scale the glyphs of the font, ie: to match the size of the fallback font
+    pub fn size_adjust(self, size_adjust: Percentage) -> Self {
+        self.descriptor("size-adjust", size_adjust)
+    }
+
+    /// the OpenType features such as `"liga" 0`
+    pub fn feature_settings(self, settings: impl fmt::Display) -> Self {
+        self.descriptor("font-feature-settings", settings)
+    }
+
+    /// the OpenType variation axes such as `"wght" 400`
+    pub fn variation_settings(self, settings: impl fmt::Display) -> Self {
+        self.descriptor("font-variation-settings", settings)
+    }
+
+    fn descriptor(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        let value = value.to_string();
+        match self
+            .descriptors
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.descriptors.push((name, value)),
+        }
+        self
+    }
+
+    /// the name of the font family
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// the value of `font-family` with the fallback families, ie: `"Inter", sans-serif`
+    pub fn family_list(&self, fallbacks: impl IntoIterator<Item = impl ToString>) -> List {
+        let mut list = List::comma([quoted(&self.family)]);
+        for fallback in fallbacks {
+            list.push(fallback.to_string());
+        }
+        list
+    }
+
+    /// the descriptors together with their values, in the order they are written
+    /// an array of the blocks of the font faces,
+    /// each font face is written in its own `@font-face`
+    pub fn blocks(font_faces: &[FontFace]) -> Result<json::JsonValue, FontFaceError> {
+        let blocks = font_faces.iter().map(json::JsonValue::try_from);
+        Ok(json::JsonValue::Array(blocks.collect::<Result<_, _>>()?))
+    }
+
+    fn descriptor_values(&self) -> Vec<(&'static str, String)> {
+        let mut values = vec![("font-family", quoted(&self.family).to_string())];
+        let sources = self.sources.iter().map(|source| source.to_string());
+        values.push(("src", sources.collect::<Vec<_>>().join(", ")));
+        if let Some((min, max)) = self.weight {
+            values.push(("font-weight", range_value(min, max)));
+        }
+        if let Some((min, max)) = self.stretch {
+            values.push(("font-stretch", range_value(min, max)));
+        }
+        if let Some(style) = self.style {
+            values.push(("font-style", style.to_string()));
+        }
+        if let Some(display) = self.display {
+            values.push(("font-display", display.to_string()));
+        }
+        if let Some(unicode_range) = self.unicode_range_value() {
+            values.push(("unicode-range", unicode_range));
+        }
+        values.extend(self.descriptors.iter().cloned());
+        values
+    }
+
+    fn unicode_range_value(&self) -> Option<String> {
+        if self.unicode_ranges.is_empty() {
+            return None;
+        }
+        let ranges = self.unicode_ranges.iter().map(|(start, end)| {
+            if start == end {
+                format!("U+{:X}", start)
+            } else {
+                format!("U+{:X}-{:X}", start, end)
+            }
+        });
+        Some(ranges.collect::<Vec<_>>().join(", "))
+    }
+
+    /// check that there is a source and that the ranges are valid
+    pub fn validate(&self) -> Result<(), FontFaceError> {
+        if self.sources.is_empty() {
+            return Err(FontFaceError::MissingSource);
+        }
+        if let Some((min, max)) = self.weight {
+            for weight in [min, max] {
+                if !(1..=1000).contains(&weight) {
+                    return Err(FontFaceError::WeightOutOfRange(weight));
+                }
+            }
+            if min > max {
+                return Err(FontFaceError::UnorderedRange {
+                    descriptor: "font-weight",
+                });
+            }
+        }
+        if let Some((min, max)) = self.stretch {
+            if min.value() > max.value() {
+                return Err(FontFaceError::UnorderedRange {
+                    descriptor: "font-stretch",
+                });
+            }
+        }
+        for &(start, end) in self.unicode_ranges.iter() {
+            if start > end || end > 0x10ffff {
+                return Err(FontFaceError::InvalidUnicodeRange { start, end });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// a single value when the range has the same start and end, ie: `400` instead of `400 400`
+fn range_value<T: PartialEq + fmt::Display>(min: T, max: T) -> String {
+    if min == max {
+        min.to_string()
+    } else {
+        format!("{} {}", min, max)
+    }
+}
+
+/// the block of the at-rule, the descriptors together with their values
+impl TryFrom<&FontFace> for json::JsonValue {
+    type Error = FontFaceError;
+
+    fn try_from(font_face: &FontFace) -> Result<Self, FontFaceError> {
+        font_face.validate()?;
+        let mut block = json::JsonValue::new_object();
+        for (name, value) in font_face.descriptor_values() {
+            block[name] = value.into();
+        }
+        Ok(block)
+    }
+}
+
+impl TryFrom<FontFace> for json::JsonValue {
+    type Error = FontFaceError;
+
+    fn try_from(font_face: FontFace) -> Result<Self, FontFaceError> {
+        json::JsonValue::try_from(&font_face)
+    }
+}
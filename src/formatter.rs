@@ -0,0 +1,193 @@
+//! provides the layout used when rendering the generated css
+use crate::value::split_top_level;
+use std::fmt;
+
+/// The string used for one level of indentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// no indentation
+    None,
+    /// indent with n spaces per level
+    Spaces(usize),
+    /// indent with n tabs per level
+    Tabs(usize),
+}
+
+/// The line break used between lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Newline {
+    /// everything is written in a single line
+    None,
+    /// unix style `\n`
+    Lf,
+    /// windows style `\r\n`
+    CrLf,
+}
+
+/// Controls how the css is laid out when it is rendered.
+///
+/// Use [`Formatter::compact`] for the minified output of `jss!` and
+/// [`Formatter::pretty`] for the output of `jss_pretty!`.
+/// Other layouts can be derived from those using struct update syntax.
+/// The formatter is part of the [`Config`](crate::Config) used when rendering.
+/// ```rust
+/// use jss::{Config, Formatter, formatter::Indent};
+///
+/// let formatter = Formatter {
+///     indent: Indent::Tabs(1),
+///     ..Formatter::pretty()
+/// };
+/// let css = jss::process_css(None, &jss::json::object! {
+///     ".layer": { display: "block" }
+/// }, &Config::from(formatter));
+/// assert_eq!("\n.layer {\n\tdisplay: block;\n}\n", css);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formatter {
+    /// indentation for each nesting level
+    pub indent: Indent,
+    /// the line break to use
+    pub newline: Newline,
+    /// put a space after `:` in declarations and before `{` in rules
+    pub spacing: bool,
+    /// put an empty line in between sibling rules
+    pub blank_line_between_rules: bool,
+    /// put an empty line after the opening brace and before the closing brace
+    /// of a block which has nested rules, such as `@media`
+    pub blank_line_around_nested_rules: bool,
+    /// put each selector of a comma separated selector list in its own line
+    pub selector_per_line: bool,
+    /// emit a line break before the first rule
+    pub leading_newline: bool,
+    /// emit a line break after the last rule
+    pub final_newline: bool,
+}
+
+impl Formatter {
+    /// no whitespace at all, this is what `jss!` uses
+    pub fn compact() -> Self {
+        Self {
+            indent: Indent::None,
+            newline: Newline::None,
+            spacing: false,
+            blank_line_between_rules: false,
+            blank_line_around_nested_rules: false,
+            selector_per_line: false,
+            leading_newline: false,
+            final_newline: false,
+        }
+    }
+
+    /// four spaces indentation with each declaration in its own line,
+    /// this is what `jss_pretty!` uses
+    pub fn pretty() -> Self {
+        Self {
+            indent: Indent::Spaces(4),
+            newline: Newline::Lf,
+            spacing: true,
+            blank_line_between_rules: false,
+            blank_line_around_nested_rules: true,
+            selector_per_line: false,
+            leading_newline: true,
+            final_newline: true,
+        }
+    }
+
+    /// write the indentation for the nesting level `n`
+    pub(crate) fn write_indent(&self, w: &mut impl fmt::Write, n: usize) -> fmt::Result {
+        let (c, width) = match self.indent {
+            Indent::None => return Ok(()),
+            Indent::Spaces(width) => (' ', width),
+            Indent::Tabs(width) => ('\t', width),
+        };
+        for _ in 0..width * n {
+            w.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// the line break string
+    pub(crate) fn newline(&self) -> &'static str {
+        match self.newline {
+            Newline::None => "",
+            Newline::Lf => "\n",
+            Newline::CrLf => "\r\n",
+        }
+    }
+
+    /// write the empty line at the start and at the end of a block which has nested rules
+    pub(crate) fn write_nested_padding(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        if self.blank_line_around_nested_rules {
+            w.write_str(self.newline())?;
+        }
+        Ok(())
+    }
+
+    /// the separator in between the property name and its value
+    pub(crate) fn colon(&self) -> &'static str {
+        if self.spacing {
+            ": "
+        } else {
+            ":"
+        }
+    }
+
+    /// the separator in between the selector and the opening brace
+    pub(crate) fn brace_open(&self) -> &'static str {
+        if self.spacing {
+            " {"
+        } else {
+            "{"
+        }
+    }
+
+    /// write the selector list, putting each selector in its own line
+    /// when `selector_per_line` is set
+    pub(crate) fn write_selector(
+        &self,
+        w: &mut impl fmt::Write,
+        indent: usize,
+        selector: &str,
+    ) -> fmt::Result {
+        if !self.selector_per_line || self.newline == Newline::None {
+            return w.write_str(selector);
+        }
+        for (i, part) in split_top_level(selector, ',').iter().enumerate() {
+            if i > 0 {
+                w.write_char(',')?;
+                w.write_str(self.newline())?;
+                self.write_indent(w, indent)?;
+            }
+            w.write_str(part.trim())?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selector_per_line() {
+        let formatter = Formatter {
+            selector_per_line: true,
+            ..Formatter::pretty()
+        };
+        let mut buffer = String::new();
+        formatter.write_selector(&mut buffer, 1, "a, b").unwrap();
+        assert_eq!("a,\n    b", buffer);
+
+        let mut buffer = String::new();
+        formatter
+            .write_selector(&mut buffer, 0, ":is(a, b),c")
+            .unwrap();
+        assert_eq!(":is(a, b),\nc", buffer);
+    }
+}
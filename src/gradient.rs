@@ -0,0 +1,696 @@
+//! builders for the css gradients, `linear-gradient()`, `radial-gradient()`, `conic-gradient()`
+//! and their `repeating-` variants
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! let gradient = linear_gradient()
+//!     .to(SideOrCorner::Right)
+//!     .stop(Color::rgb(255, 0, 0))
+//!     .stop_at("blue", percent(50));
+//! assert_eq!(
+//!     "linear-gradient(to right, rgb(255, 0, 0), blue 50%)",
+//!     gradient.to_string()
+//! );
+//!
+//! let wheel = conic_gradient()
+//!     .in_hue(ColorSpace::Oklch, HueInterpolation::Longer)
+//!     .stop("red")
+//!     .stop("red");
+//! assert_eq!("conic-gradient(in oklch longer hue, red, red)", wheel.to_string());
+//! ```
+
+use crate::color::{Color, ColorSpace, ModernColor};
+use crate::fns::Var;
+use crate::units::{Angle, AngleUnit, Length, LengthUnit};
+use crate::value::{impl_str_eq, Value};
+use std::fmt;
+
+/// The side or corner which a linear gradient goes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideOrCorner {
+    /// `to top`
+    Top,
+    /// `to right`
+    Right,
+    /// `to bottom`
+    Bottom,
+    /// `to left`
+    Left,
+    /// `to top left`
+    TopLeft,
+    /// `to top right`
+    TopRight,
+    /// `to bottom left`
+    BottomLeft,
+    /// `to bottom right`
+    BottomRight,
+}
+
+impl fmt::Display for SideOrCorner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = match self {
+            SideOrCorner::Top => "top",
+            SideOrCorner::Right => "right",
+            SideOrCorner::Bottom => "bottom",
+            SideOrCorner::Left => "left",
+            SideOrCorner::TopLeft => "top left",
+            SideOrCorner::TopRight => "top right",
+            SideOrCorner::BottomLeft => "bottom left",
+            SideOrCorner::BottomRight => "bottom right",
+        };
+        write!(f, "to {}", side)
+    }
+}
+
+/// The direction of a linear gradient
+#[derive(Debug, Clone, PartialEq)]
+pub enum Direction {
+    /// an angle, `0deg` goes to the top and `90deg` goes to the right
+    Angle(Angle),
+    /// towards a side or corner
+    To(SideOrCorner),
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Angle(angle) => write!(f, "{}", angle),
+            Direction::To(side) => write!(f, "{}", side),
+        }
+    }
+}
+
+/// The shape of a radial gradient
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// `circle`
+    Circle,
+    /// `ellipse`
+    Ellipse,
+}
+
+/// The size of the ending shape of a radial gradient
+#[derive(Debug, Clone, PartialEq)]
+pub enum Extent {
+    /// `closest-side`
+    ClosestSide,
+    /// `closest-corner`
+    ClosestCorner,
+    /// `farthest-side`
+    FarthestSide,
+    /// `farthest-corner`, the default
+    FarthestCorner,
+    /// the radius of a circle
+    Radius(Length),
+    /// the horizontal and vertical radius of an ellipse
+    Radii(Length, Length),
+}
+
+impl fmt::Display for Extent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Extent::ClosestSide => write!(f, "closest-side"),
+            Extent::ClosestCorner => write!(f, "closest-corner"),
+            Extent::FarthestSide => write!(f, "farthest-side"),
+            Extent::FarthestCorner => write!(f, "farthest-corner"),
+            Extent::Radius(radius) => write!(f, "{}", radius),
+            Extent::Radii(x, y) => write!(f, "{} {}", x, y),
+        }
+    }
+}
+
+/// How the hue is interpolated in a polar color space such as oklch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HueInterpolation {
+    /// `shorter hue`, the default
+    Shorter,
+    /// `longer hue`
+    Longer,
+    /// `increasing hue`
+    Increasing,
+    /// `decreasing hue`
+    Decreasing,
+}
+
+impl fmt::Display for HueInterpolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HueInterpolation::Shorter => write!(f, "shorter hue"),
+            HueInterpolation::Longer => write!(f, "longer hue"),
+            HueInterpolation::Increasing => write!(f, "increasing hue"),
+            HueInterpolation::Decreasing => write!(f, "decreasing hue"),
+        }
+    }
+}
+
+/// The color of a color stop
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopColor {
+    /// a [`Color`] or a [`ModernColor`]
+    Color(ModernColor),
+    /// a custom property which holds the color, ie: `var(--accent)`
+    Var(Var),
+    /// a named color such as `red`, `transparent` or `currentcolor`
+    Named(String),
+}
+
+impl From<Color> for StopColor {
+    fn from(color: Color) -> Self {
+        StopColor::Color(color.into())
+    }
+}
+
+impl From<ModernColor> for StopColor {
+    fn from(color: ModernColor) -> Self {
+        StopColor::Color(color)
+    }
+}
+
+impl From<Var> for StopColor {
+    fn from(var: Var) -> Self {
+        StopColor::Var(var)
+    }
+}
+
+/// the name is checked by [`validate`](LinearGradient::validate)
+impl From<&str> for StopColor {
+    fn from(name: &str) -> Self {
+        StopColor::Named(name.to_string())
+    }
+}
+
+impl fmt::Display for StopColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StopColor::Color(color) => write!(f, "{}", color),
+            StopColor::Var(var) => write!(f, "{}", var),
+            StopColor::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The position of a color stop of a conic gradient, the css `<angle-percentage>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnglePercentage {
+    /// an angle, ie: `90deg`
+    Angle(Angle),
+    /// a percentage of the full turn, ie: `25%`, the other lengths are rejected by
+    /// [`validate`](ConicGradient::validate)
+    Percentage(Length),
+}
+
+impl From<Angle> for AnglePercentage {
+    fn from(angle: Angle) -> Self {
+        AnglePercentage::Angle(angle)
+    }
+}
+
+impl From<Length> for AnglePercentage {
+    fn from(percentage: Length) -> Self {
+        AnglePercentage::Percentage(percentage)
+    }
+}
+
+impl fmt::Display for AnglePercentage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnglePercentage::Angle(angle) => write!(f, "{}", angle),
+            AnglePercentage::Percentage(percentage) => write!(f, "{}", percentage),
+        }
+    }
+}
+
+/// the position of a color stop which can be checked against the previous position
+trait Position: Copy + fmt::Display {
+    /// whether the position is valid for the gradient
+    fn is_valid(&self) -> bool;
+    /// whether the position is before the previous position,
+    /// `false` when they can not be compared such as `50%` and `10px`
+    fn is_before(&self, previous: &Self) -> bool;
+}
+
+impl Position for Length {
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    fn is_before(&self, previous: &Self) -> bool {
+        self.to(previous.unit())
+            .is_some_and(|converted| converted.value() < previous.value())
+    }
+}
+
+impl AnglePercentage {
+    /// the position as a fraction of the full turn
+    fn turns(&self) -> Option<f64> {
+        match self {
+            AnglePercentage::Angle(angle) => angle.to(AngleUnit::Turn).map(|a| a.value()),
+            AnglePercentage::Percentage(percentage) if percentage.unit() == LengthUnit::Percent => {
+                Some(percentage.value() / 100.0)
+            }
+            AnglePercentage::Percentage(_) => None,
+        }
+    }
+}
+
+impl Position for AnglePercentage {
+    fn is_valid(&self) -> bool {
+        self.turns().is_some()
+    }
+
+    fn is_before(&self, previous: &Self) -> bool {
+        match (self.turns(), previous.turns()) {
+            (Some(turns), Some(previous)) => turns < previous,
+            _ => false,
+        }
+    }
+}
+
+/// A color stop or a color hint of a gradient,
+/// the positions are lengths or percentages in linear and radial gradients
+/// and [`AnglePercentage`] in conic gradients
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stop<P> {
+    /// a color with up to 2 positions
+    Color(StopColor, Vec<P>),
+    /// the position of the midpoint in between the 2 adjacent color stops
+    Hint(P),
+}
+
+impl<P: fmt::Display> fmt::Display for Stop<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Color(color, positions) => {
+                write!(f, "{}", color)?;
+                for position in positions {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
+            }
+            Stop::Hint(position) => write!(f, "{}", position),
+        }
+    }
+}
+
+/// The error when the gradient would be an invalid css value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GradientError {
+    /// a gradient needs at least 2 color stops
+    TooFewStops,
+    /// a color hint needs a color stop before and after it
+    MisplacedHint,
+    /// the position of a stop is before the position of the previous stop
+    UnorderedStops {
+        /// the position of the previous stop
+        previous: String,
+        /// the position which is before the previous one
+        next: String,
+    },
+    /// the hue interpolation is only for the polar color spaces such as oklch
+    HueInRectangularSpace(ColorSpace),
+    /// the named color is not a css color keyword
+    UnknownColor(String),
+    /// the position of a conic gradient is neither an angle nor a percentage
+    InvalidPosition(String),
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GradientError::TooFewStops => write!(f, "a gradient needs at least 2 color stops"),
+            GradientError::MisplacedHint => {
+                write!(f, "a color hint must be in between 2 color stops")
+            }
+            GradientError::UnorderedStops { previous, next } => write!(
+                f,
+                "the stop position {} is before the previous position {}",
+                next, previous
+            ),
+            GradientError::HueInRectangularSpace(space) => write!(
+                f,
+                "hue interpolation is not supported in the rectangular color space {}",
+                space
+            ),
+            GradientError::UnknownColor(name) => write!(f, "`{}` is not a named color", name),
+            GradientError::InvalidPosition(position) => write!(
+                f,
+                "the position {} is neither an angle nor a percentage",
+                position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GradientError {}
+
+/// check the color stops and the interpolation color space of a gradient
+fn validate<P: Position>(
+    stops: &[Stop<P>],
+    interpolation: &Option<(ColorSpace, Option<HueInterpolation>)>,
+) -> Result<(), GradientError> {
+    if let Some((space, Some(_))) = interpolation {
+        if !matches!(space, ColorSpace::Oklch | ColorSpace::Lch) {
+            return Err(GradientError::HueInRectangularSpace(*space));
+        }
+    }
+    let colors = stops
+        .iter()
+        .filter(|stop| matches!(stop, Stop::Color(..)))
+        .count();
+    if colors < 2 {
+        return Err(GradientError::TooFewStops);
+    }
+    let misplaced_hint = stops
+        .windows(2)
+        .any(|pair| matches!(pair, [Stop::Hint(_), Stop::Hint(_)]))
+        || matches!(stops.first(), Some(Stop::Hint(_)))
+        || matches!(stops.last(), Some(Stop::Hint(_)));
+    if misplaced_hint {
+        return Err(GradientError::MisplacedHint);
+    }
+    let unknown_color = stops.iter().find_map(|stop| match stop {
+        Stop::Color(StopColor::Named(name), _)
+            if Color::named(name).is_none() && !name.eq_ignore_ascii_case("currentcolor") =>
+        {
+            Some(name)
+        }
+        _ => None,
+    });
+    if let Some(name) = unknown_color {
+        return Err(GradientError::UnknownColor(name.clone()));
+    }
+    let positions = stops.iter().flat_map(|stop| match stop {
+        Stop::Color(_, positions) => positions.clone(),
+        Stop::Hint(position) => vec![*position],
+    });
+    let mut previous: Option<P> = None;
+    for position in positions {
+        if !position.is_valid() {
+            return Err(GradientError::InvalidPosition(position.to_string()));
+        }
+        if let Some(prev) = previous {
+            if position.is_before(&prev) {
+                return Err(GradientError::UnorderedStops {
+                    previous: prev.to_string(),
+                    next: position.to_string(),
+                });
+            }
+        }
+        previous = Some(position);
+    }
+    Ok(())
+}
+
+/// write the color interpolation, ie: `in oklch longer hue`
+fn write_interpolation(
+    f: &mut fmt::Formatter,
+    (space, hue): &(ColorSpace, Option<HueInterpolation>),
+) -> fmt::Result {
+    write!(f, "in {}", space)?;
+    if let Some(hue) = hue {
+        write!(f, " {}", hue)?;
+    }
+    Ok(())
+}
+
+/// A `linear-gradient()` or `repeating-linear-gradient()`
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinearGradient {
+    repeating: bool,
+    direction: Option<Direction>,
+    interpolation: Option<(ColorSpace, Option<HueInterpolation>)>,
+    stops: Vec<Stop<Length>>,
+}
+
+/// A `radial-gradient()` or `repeating-radial-gradient()`
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/radial-gradient)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RadialGradient {
+    repeating: bool,
+    shape: Option<Shape>,
+    extent: Option<Extent>,
+    position: Option<String>,
+    interpolation: Option<(ColorSpace, Option<HueInterpolation>)>,
+    stops: Vec<Stop<Length>>,
+}
+
+/// A `conic-gradient()` or `repeating-conic-gradient()`,
+/// the stop positions are angles or percentages of the full turn
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/conic-gradient)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConicGradient {
+    repeating: bool,
+    from: Option<Angle>,
+    position: Option<String>,
+    interpolation: Option<(ColorSpace, Option<HueInterpolation>)>,
+    stops: Vec<Stop<AnglePercentage>>,
+}
+
+impl LinearGradient {
+    /// the gradient goes in the direction of the angle
+    pub fn angle(mut self, angle: Angle) -> Self {
+        self.direction = Some(Direction::Angle(angle));
+        self
+    }
+
+    /// the gradient goes towards the side or corner
+    pub fn to(mut self, side: SideOrCorner) -> Self {
+        self.direction = Some(Direction::To(side));
+        self
+    }
+
+    fn write_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        if let Some(direction) = &self.direction {
+            parts.push(direction.to_string());
+        }
+        write_parts(f, parts, &self.interpolation)
+    }
+}
+
+impl RadialGradient {
+    /// the ending shape of the gradient
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    /// the size of the ending shape
+    pub fn extent(mut self, extent: Extent) -> Self {
+        self.extent = Some(extent);
+        self
+    }
+
+    /// the center of the gradient, ie: `.at(percent(50), "top")`
+    pub fn at(mut self, x: impl fmt::Display, y: impl fmt::Display) -> Self {
+        self.position = Some(format!("{} {}", x, y));
+        self
+    }
+
+    fn write_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        match self.shape {
+            Some(Shape::Circle) => parts.push("circle".to_string()),
+            Some(Shape::Ellipse) => parts.push("ellipse".to_string()),
+            None => (),
+        }
+        if let Some(extent) = &self.extent {
+            parts.push(extent.to_string());
+        }
+        if let Some(position) = &self.position {
+            parts.push(format!("at {}", position));
+        }
+        write_parts(f, parts, &self.interpolation)
+    }
+}
+
+impl ConicGradient {
+    /// the angle where the gradient starts, `0deg` is at the top
+    pub fn from(mut self, angle: Angle) -> Self {
+        self.from = Some(angle);
+        self
+    }
+
+    /// the center of the gradient, ie: `.at(percent(50), "top")`
+    pub fn at(mut self, x: impl fmt::Display, y: impl fmt::Display) -> Self {
+        self.position = Some(format!("{} {}", x, y));
+        self
+    }
+
+    fn write_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        if let Some(from) = &self.from {
+            parts.push(format!("from {}", from));
+        }
+        if let Some(position) = &self.position {
+            parts.push(format!("at {}", position));
+        }
+        write_parts(f, parts, &self.interpolation)
+    }
+}
+
+/// write the space separated parts before the color stops followed by a comma
+fn write_parts(
+    f: &mut fmt::Formatter,
+    parts: Vec<String>,
+    interpolation: &Option<(ColorSpace, Option<HueInterpolation>)>,
+) -> fmt::Result {
+    if parts.is_empty() && interpolation.is_none() {
+        return Ok(());
+    }
+    write!(f, "{}", parts.join(" "))?;
+    if let Some(interpolation) = interpolation {
+        if !parts.is_empty() {
+            write!(f, " ")?;
+        }
+        write_interpolation(f, interpolation)?;
+    }
+    write!(f, ", ")
+}
+
+/// the color stops, validation and conversions shared by the gradients
+macro_rules! impl_gradient {
+    ($($ty:ident($name:literal, $position:ty);)*) => {
+        $(
+            impl $ty {
+                /// make it the `repeating-` variant of the gradient
+                pub fn repeating(mut self) -> Self {
+                    self.repeating = true;
+                    self
+                }
+
+                /// interpolate the colors in the color space
+                pub fn in_space(mut self, space: ColorSpace) -> Self {
+                    self.interpolation = Some((space, None));
+                    self
+                }
+
+                /// interpolate the colors in the polar color space with the hue interpolation
+                pub fn in_hue(mut self, space: ColorSpace, hue: HueInterpolation) -> Self {
+                    self.interpolation = Some((space, Some(hue)));
+                    self
+                }
+
+                /// add a color stop without a position,
+                /// the color can be a `Color`, `ModernColor`, `var()` or a named color
+                pub fn stop(mut self, color: impl Into<StopColor>) -> Self {
+                    self.stops.push(Stop::Color(color.into(), vec![]));
+                    self
+                }
+
+                /// add a color stop at the position
+                pub fn stop_at(
+                    mut self,
+                    color: impl Into<StopColor>,
+                    position: impl Into<$position>,
+                ) -> Self {
+                    self.stops
+                        .push(Stop::Color(color.into(), vec![position.into()]));
+                    self
+                }
+
+                /// add a color stop which spans from the start to the end position
+                pub fn stop_between(
+                    mut self,
+                    color: impl Into<StopColor>,
+                    start: impl Into<$position>,
+                    end: impl Into<$position>,
+                ) -> Self {
+                    self.stops
+                        .push(Stop::Color(color.into(), vec![start.into(), end.into()]));
+                    self
+                }
+
+                /// add a color hint, the position of the midpoint in between the adjacent color stops
+                pub fn hint(mut self, position: impl Into<$position>) -> Self {
+                    self.stops.push(Stop::Hint(position.into()));
+                    self
+                }
+
+                /// the color stops and hints of the gradient
+                pub fn stops(&self) -> &[Stop<$position>] {
+                    &self.stops
+                }
+
+                /// check that there are at least 2 color stops, the hints are in between
+                /// color stops, the named colors are known and the positions are in order
+                pub fn validate(&self) -> Result<(), GradientError> {
+                    validate(&self.stops, &self.interpolation)
+                }
+            }
+
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    if self.repeating {
+                        write!(f, "repeating-")?;
+                    }
+                    write!(f, "{}(", $name)?;
+                    self.write_header(f)?;
+                    for (i, stop) in self.stops.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", stop)?;
+                    }
+                    write!(f, ")")
+                }
+            }
+
+            impl TryFrom<$ty> for Value {
+                type Error = GradientError;
+
+                fn try_from(gradient: $ty) -> Result<Self, GradientError> {
+                    gradient.validate()?;
+                    Ok(Value::String(gradient.to_string()))
+                }
+            }
+
+            impl TryFrom<$ty> for json::JsonValue {
+                type Error = GradientError;
+
+                fn try_from(gradient: $ty) -> Result<Self, GradientError> {
+                    Ok(Value::try_from(gradient)?.into())
+                }
+            }
+
+            impl_str_eq!($ty);
+        )*
+    };
+}
+
+impl_gradient! {
+    LinearGradient("linear-gradient", Length);
+    RadialGradient("radial-gradient", Length);
+    ConicGradient("conic-gradient", AnglePercentage);
+}
+
+/// a `linear-gradient()` which goes to the bottom unless a direction is given
+pub fn linear_gradient() -> LinearGradient {
+    LinearGradient::default()
+}
+
+/// a `repeating-linear-gradient()`
+pub fn repeating_linear_gradient() -> LinearGradient {
+    LinearGradient::default().repeating()
+}
+
+/// a `radial-gradient()` which is an ellipse at the center unless a shape and position is given
+pub fn radial_gradient() -> RadialGradient {
+    RadialGradient::default()
+}
+
+/// a `repeating-radial-gradient()`
+pub fn repeating_radial_gradient() -> RadialGradient {
+    RadialGradient::default().repeating()
+}
+
+/// a `conic-gradient()` which starts at the top unless a `from` angle is given
+pub fn conic_gradient() -> ConicGradient {
+    ConicGradient::default()
+}
+
+/// a `repeating-conic-gradient()`
+pub fn repeating_conic_gradient() -> ConicGradient {
+    ConicGradient::default().repeating()
+}
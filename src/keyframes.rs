@@ -0,0 +1,182 @@
+//! a builder for the `@keyframes` at-rule with typed offsets
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::json::{object, JsonValue};
+//!
+//! let fade_in = Keyframes::new("fade-in")
+//!     .frame(Offset::From, object! { opacity: 0 })
+//!     .frame(0.5, object! { opacity: 0.8 })
+//!     .frame(Offset::To, object! { opacity: 1 });
+//!
+//! let css = jss::jss_ns!("modal",
+//!     [fade_in.selector()]: JsonValue::try_from(&fade_in).unwrap(),
+//!     ".dialog": {
+//!         animation_name: fade_in.name(),
+//!     },
+//! );
+//! assert_eq!(
+//!     "@keyframes fade-in{from{opacity:0;}50%{opacity:0.8;}to{opacity:1;}}.modal__dialog{animation-name:fade-in;}",
+//!     css
+//! );
+//! ```
+
+use crate::units;
+use std::fmt;
+
+/// The offset of a keyframe, in the range of 0.0..=1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    /// `from`, the same as `0%`
+    From,
+    /// `to`, the same as `100%`
+    To,
+    /// the progress of the animation, rendered as a percentage
+    At(f64),
+}
+
+impl Offset {
+    /// the offset as a progress in the range of 0.0..=1.0
+    pub fn progress(&self) -> f64 {
+        match self {
+            Offset::From => 0.0,
+            Offset::To => 1.0,
+            Offset::At(progress) => *progress,
+        }
+    }
+}
+
+impl From<f64> for Offset {
+    fn from(progress: f64) -> Self {
+        Offset::At(progress)
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Offset::From => write!(f, "from"),
+            Offset::To => write!(f, "to"),
+            Offset::At(progress) => {
+                units::write_number(f, progress * 100.0)?;
+                write!(f, "%")
+            }
+        }
+    }
+}
+
+/// The error when the keyframes would be an invalid css at-rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyframesError {
+    /// the offset is not in the range of 0.0..=1.0
+    OutOfRange(f64),
+}
+
+impl fmt::Display for KeyframesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyframesError::OutOfRange(progress) => {
+                write!(
+                    f,
+                    "keyframe offset {} is not in the range of 0 to 1",
+                    progress
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeyframesError {}
+
+const KEYFRAMES: &str = "@keyframes ";
+
+/// The keyframes of an animation, rendered as `@keyframes <name>`.
+///
+/// The keyframes are kept in the order of their offsets, and the keyframes with the same offset
+/// are merged into a single block with the properties of the later keyframe taking precedence.
+/// Properties which can not be animated are reported by the [`Lint`](crate::lint::Lint)
+/// of the config while rendering.
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@keyframes)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes {
+    /// `@keyframes <name>`
+    selector: String,
+    frames: Vec<(Offset, json::JsonValue)>,
+}
+
+impl Keyframes {
+    /// keyframes without any frames yet, the name is used in `animation-name`
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            selector: format!("{}{}", KEYFRAMES, name.to_string()),
+            frames: vec![],
+        }
+    }
+
+    /// add a keyframe with its style properties, ie: `jss::json::object!{ opacity: 0 }`.
+    /// The keyframe is inserted at the position of its offset,
+    /// and its properties are merged into the keyframe which has the same offset
+    pub fn frame(mut self, offset: impl Into<Offset>, properties: json::JsonValue) -> Self {
+        let offset = offset.into();
+        let progress = offset.progress();
+        if let Some((_, existing)) = self
+            .frames
+            .iter_mut()
+            .find(|(other, _)| other.progress() == progress)
+        {
+            for (prop, value) in properties.entries() {
+                existing[prop] = value.clone();
+            }
+        } else {
+            let index = self
+                .frames
+                .iter()
+                .position(|(other, _)| other.progress() > progress)
+                .unwrap_or(self.frames.len());
+            self.frames.insert(index, (offset, properties));
+        }
+        self
+    }
+
+    /// the name of the animation
+    pub fn name(&self) -> &str {
+        &self.selector[KEYFRAMES.len()..]
+    }
+
+    /// the at-rule selector, `@keyframes <name>`
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    /// check that the offsets are in range
+    pub fn validate(&self) -> Result<(), KeyframesError> {
+        for (offset, _) in self.frames.iter() {
+            let progress = offset.progress();
+            if !(0.0..=1.0).contains(&progress) {
+                return Err(KeyframesError::OutOfRange(progress));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// the block of the at-rule, the keyframe selectors together with their properties
+impl TryFrom<&Keyframes> for json::JsonValue {
+    type Error = KeyframesError;
+
+    fn try_from(keyframes: &Keyframes) -> Result<Self, KeyframesError> {
+        keyframes.validate()?;
+        let mut block = json::JsonValue::new_object();
+        for (offset, properties) in keyframes.frames.iter() {
+            block[offset.to_string()] = properties.clone();
+        }
+        Ok(block)
+    }
+}
+
+impl TryFrom<Keyframes> for json::JsonValue {
+    type Error = KeyframesError;
+
+    fn try_from(keyframes: Keyframes) -> Result<Self, KeyframesError> {
+        json::JsonValue::try_from(&keyframes)
+    }
+}
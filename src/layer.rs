@@ -0,0 +1,145 @@
+//! cascade layers, a stylesheet of fragments assigned to the `@layer` in the declared order
+//! ```rust
+//! use jss::layer::Layers;
+//! use jss::json::object;
+//! use jss::Config;
+//!
+//! let css = Layers::new(["reset", "base", "components"])
+//!     .add("components", Some("button"), object! {
+//!         ".": { padding: "4px 8px" },
+//!     })
+//!     .add("reset", None, object! {
+//!         "*": { margin: 0 },
+//!     })
+//!     .process_css(&Config::compact());
+//! assert_eq!(
+//!     "@layer reset, base, components;@layer reset{*{margin:0;}}@layer components{.button{padding:4px 8px;}}",
+//!     css
+//! );
+//! ```
+//!
+//! The layer statement and the layer blocks can also be written directly in `jss!`
+//! ```rust
+//! let css = jss::jss!(
+//!     "@layer": ["reset", "base"],
+//!     "@layer base": {
+//!         "body": { margin: 0 },
+//!     },
+//! );
+//! assert_eq!("@layer reset, base;@layer base{body{margin:0;}}", css);
+//! ```
+
+use crate::config::Render;
+use crate::lint::Warning;
+use crate::{write_css_selector_map, write_statement, Config};
+use std::fmt;
+
+/// A stylesheet made of fragments which are assigned to a cascade layer.
+///
+/// The layer statement with the order of the layers is written first,
+/// followed by a `@layer` block for each layer which has fragments.
+/// The styles in a later layer take precedence over the styles in an earlier layer
+/// regardless of the specificity of their selectors.
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@layer)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Layers {
+    order: Vec<String>,
+    /// the layer, the namespace and the css of the fragments in the order they were added
+    fragments: Vec<(String, Option<String>, json::JsonValue)>,
+}
+
+impl Layers {
+    /// the layers from the lowest to the highest precedence
+    pub fn new(order: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            order: order.into_iter().map(|layer| layer.to_string()).collect(),
+            fragments: vec![],
+        }
+    }
+
+    /// assign the css to the layer, the selectors of the css are namespaced the same way as `jss_ns!`.
+    /// A layer which is not in the order is added as the layer with the highest precedence
+    pub fn add(mut self, layer: &str, namespace: Option<&str>, css: json::JsonValue) -> Self {
+        if !self.order.iter().any(|existing| existing == layer) {
+            self.order.push(layer.to_string());
+        }
+        self.fragments
+            .push((layer.to_string(), namespace.map(ToString::to_string), css));
+        self
+    }
+
+    /// the layers from the lowest to the highest precedence
+    pub fn order(&self) -> impl Iterator<Item = &str> {
+        self.order.iter().map(String::as_str)
+    }
+
+    /// render the layer statement and the layer blocks
+    pub fn process_css(&self, config: &Config) -> String {
+        let mut buffer = String::new();
+        self.write_css(&mut buffer, config)
+            .expect("must write to String");
+        buffer
+    }
+
+    /// render the layer statement and the layer blocks,
+    /// together with the warnings found by the [`Lint`](crate::lint::Lint) of the config
+    pub fn process_css_with_warnings(&self, config: &Config) -> (String, Vec<Warning>) {
+        let render = Render::new(config);
+        let mut buffer = String::new();
+        self.write_layers(&mut buffer, &render)
+            .expect("must write to String");
+        (buffer, render.into_warnings())
+    }
+
+    /// write the layer statement and the layer blocks into a `fmt::Write` sink
+    pub fn write_css(&self, w: &mut impl fmt::Write, config: &Config) -> fmt::Result {
+        self.write_layers(w, &Render::new(config))
+    }
+
+    fn write_layers(&self, w: &mut impl fmt::Write, config: &Render) -> fmt::Result {
+        if config.formatter.leading_newline {
+            w.write_str(config.formatter.newline())?;
+        }
+        let order = json::JsonValue::Array(
+            self.order
+                .iter()
+                .map(|layer| layer.as_str().into())
+                .collect(),
+        );
+        // `@layer ;` is invalid, so there is no statement without layers
+        if !self.order.is_empty() {
+            write_statement(w, 0, "@layer", &order, config)?;
+        }
+        for layer in self.order.iter() {
+            let mut fragments = self
+                .fragments
+                .iter()
+                .filter(|(fragment_layer, _, _)| fragment_layer == layer)
+                .peekable();
+            // the layers without fragments are only in the layer statement
+            if fragments.peek().is_none() {
+                continue;
+            }
+            w.write_str(config.formatter.newline())?;
+            if config.formatter.blank_line_between_rules {
+                w.write_str(config.formatter.newline())?;
+            }
+            write!(w, "@layer {}{}", layer, config.formatter.brace_open())?;
+            w.write_str(config.formatter.newline())?;
+            config.formatter.write_nested_padding(w)?;
+            for (i, (_, namespace, css)) in fragments.enumerate() {
+                if i > 0 && config.formatter.blank_line_between_rules {
+                    w.write_str(config.formatter.newline())?;
+                }
+                write_css_selector_map(w, 1, namespace.as_deref(), css, config)?;
+                w.write_str(config.formatter.newline())?;
+            }
+            config.formatter.write_nested_padding(w)?;
+            w.write_str("}")?;
+        }
+        if config.formatter.final_newline {
+            w.write_str(config.formatter.newline())?;
+        }
+        Ok(())
+    }
+}
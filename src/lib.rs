@@ -3,22 +3,188 @@
 //! Provides convenient functions and macro to build dynamic css
 #![doc = include_str!("../README.md")]
 
+pub use config::Config;
+use config::Render;
+pub use formatter::Formatter;
 #[doc(hidden)]
 pub use json;
-pub use value::Value;
+use std::fmt;
+use std::io;
+pub use value::{List, Separator, Value};
 
 pub mod prelude {
-    pub use crate::*;
-    pub use fns::*;
-    pub use units::*;
-    pub use value::Value;
+    pub use crate::color::{Color, ColorSpace, ModernColor};
+    pub use crate::container::{ContainerQuery, ContainerType};
+    pub use crate::easing::{cubic_bezier, steps, Easing, EasingError, LinearStop, StepPosition};
+    pub use crate::filter::{
+        blur, brightness, contrast, drop_shadow, filter_url, grayscale, hue_rotate, invert,
+        opacity, saturate, sepia, Filter, FilterFunction,
+    };
+    pub use crate::fns::{
+        attr, attr_or, calc, clamp, color_mix, env, image_set, lab, lch, max, min, oklab, oklch,
+        quoted, rgb, url, var, Env, Var,
+    };
+    pub use crate::font_face::{
+        FontDisplay, FontFace, FontFaceError, FontFormat, FontSource, FontStyle,
+    };
+    pub use crate::gradient::{
+        conic_gradient, linear_gradient, radial_gradient, repeating_conic_gradient,
+        repeating_linear_gradient, repeating_radial_gradient, ConicGradient, GradientError,
+        HueInterpolation, LinearGradient, RadialGradient, SideOrCorner,
+    };
+    pub use crate::keyframes::{Keyframes, KeyframesError, Offset};
+    pub use crate::layer::Layers;
+    pub use crate::media::{Breakpoints, MediaCondition, MediaQuery, MediaType};
+    pub use crate::property::{CustomProperty, CustomPropertyError, Syntax};
+    pub use crate::shadow::{shadow, text_shadow, Shadow, Shadows, TextShadow, TextShadows};
+    pub use crate::supports::Supports;
+    pub use crate::transform::{
+        matrix, matrix3d, perspective, rotate, rotate3d, rotate_x, rotate_y, rotate_z, scale,
+        scale3d, scale_x, scale_xy, scale_y, scale_z, skew, skew_x, skew_y, translate, translate3d,
+        translate_x, translate_y, translate_z, Transform, TransformFunction,
+    };
+    pub use crate::units::*;
+    pub use crate::value::Value;
+    pub use crate::{
+        class_namespaced, json, jss, jss_ns, jss_ns_pretty, jss_pretty, process_css,
+        process_css_properties, process_css_with_warnings, selector_namespaced, style, write_css,
+        write_css_io, write_css_properties, Config, Formatter, List, Separator,
+    };
+}
+
+/// the css math functions, these are also in the prelude
+/// ```rust
+/// use jss::math::min;
+/// use jss::units::{percent, px};
+///
+/// assert_eq!("min(50%, 300px)", min(percent(50), px(300)));
+/// ```
+pub mod math {
+    pub use crate::fns::{calc, clamp, max, min};
 }
 
+pub mod color;
+pub mod config;
+pub mod container;
+pub mod easing;
+pub mod filter;
 mod fns;
+pub mod font_face;
+pub mod formatter;
+pub mod gradient;
+pub mod keyframes;
+pub mod layer;
+pub mod lint;
+pub mod media;
+pub mod property;
+pub mod shadow;
 pub mod style;
+pub mod supports;
+pub mod transform;
 pub mod units;
 mod value;
 
+/// the `json::object!` macro used by `jss!` and `style!`,
+/// except that a tuple value is written as a space separated list, ie: `("opacity", ms(200))`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __object {
+    {} => ($crate::json::JsonValue::new_object());
+
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:ident: $( $cont:tt )*) => {
+        $crate::__object!(@ENTRY($( $k => $v, )*) stringify!($key) => $($cont)*)
+    };
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:literal: $( $cont:tt )*) => {
+        $crate::__object!(@ENTRY($( $k => $v, )*) $key => $($cont)*)
+    };
+    (@ENTRY($( $k:expr => $v:expr, )*) [$key:expr]: $( $cont:tt )*) => {
+        $crate::__object!(@ENTRY($( $k => $v, )*) $key => $($cont)*)
+    };
+
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:expr => $value:tt, $( $cont:tt )+) => {
+        $crate::__object!(
+            @ENTRY($( $k => $v, )* $key => $crate::__value!($value), )
+            $( $cont )*
+        )
+    };
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:expr => $value:tt $(,)?) => {
+        $crate::__object!(@END $( $k => $v, )* $key => $crate::__value!($value), )
+    };
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:expr => $value:expr, $( $cont:tt )+) => {
+        $crate::__object!(
+            @ENTRY($( $k => $v, )* $key => $crate::__value!($value), )
+            $( $cont )*
+        )
+    };
+    (@ENTRY($( $k:expr => $v:expr, )*) $key:expr => $value:expr $(,)?) => {
+        $crate::__object!(@END $( $k => $v, )* $key => $crate::__value!($value), )
+    };
+
+    (@END $( $k:expr => $v:expr, )*) => ({
+        let mut object = $crate::json::object::Object::new();
+        $(
+            object.insert($k, $v.into());
+        )*
+        $crate::json::JsonValue::Object(object)
+    });
+
+    ($key:tt: $( $cont:tt )+) => {
+        $crate::__object!(@ENTRY() $key: $($cont)*)
+    };
+}
+
+/// the `json::array!` macro used by [`__object!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __array {
+    [] => ($crate::json::JsonValue::new_array());
+
+    [@ITEM($( $i:expr, )*) $item:tt, $( $cont:tt )+] => {
+        $crate::__array!(
+            @ITEM($( $i, )* $crate::__value!($item), )
+            $( $cont )*
+        )
+    };
+    [@ITEM($( $i:expr, )*) $item:tt $(,)?] => {
+        $crate::__array!(@END $( $i, )* $crate::__value!($item), )
+    };
+    [@ITEM($( $i:expr, )*) $item:expr, $( $cont:tt )+] => {
+        $crate::__array!(
+            @ITEM($( $i, )* $crate::__value!($item), )
+            $( $cont )*
+        )
+    };
+    [@ITEM($( $i:expr, )*) $item:expr $(,)?] => {
+        $crate::__array!(@END $( $i, )* $crate::__value!($item), )
+    };
+
+    [@END $( $i:expr, )*] => ({
+        let array: Vec<$crate::json::JsonValue> = vec![$( $i.into(), )*];
+        $crate::json::JsonValue::Array(array)
+    });
+
+    [$( $cont:tt )+] => {
+        $crate::__array!(@ITEM() $($cont)*)
+    };
+}
+
+/// a value of [`__object!`] or [`__array!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __value {
+    ( null ) => { $crate::json::Null };
+    ( [$( $token:tt )*] ) => {
+        $crate::__array![ $( $token )* ]
+    };
+    ( {$( $token:tt )*} ) => {
+        $crate::__object!{ $( $token )* }
+    };
+    ( ($first:expr, $( $rest:expr ),+ $(,)?) ) => {
+        $crate::Value::Vec(vec![$crate::Value::from($first), $( $crate::Value::from($rest) ),+])
+    };
+    { $value:expr } => { $value };
+}
+
 /// Creates css using json notation
 /// ```rust
 /// use jss::jss;
@@ -41,8 +207,8 @@ mod value;
 macro_rules! jss {
     ($($tokens:tt)+) => {
         {
-            let json = $crate::json::object!($($tokens)*);
-            $crate::process_css(None, &json, false)
+            let json = $crate::__object!($($tokens)*);
+            $crate::process_css(None, &json, &$crate::Config::compact())
         }
     };
 
@@ -76,8 +242,8 @@ macro_rules! jss {
 macro_rules! jss_pretty {
     ($($tokens:tt)+) => {
         {
-            let json = $crate::json::object!($($tokens)*);
-            $crate::process_css(None, &json, true)
+            let json = $crate::__object!($($tokens)*);
+            $crate::process_css(None, &json, &$crate::Config::pretty())
         }
     };
 
@@ -114,8 +280,8 @@ macro_rules! jss_pretty {
 macro_rules! jss_ns {
     ($namespace: tt, $($tokens:tt)+) => {
         {
-            let json = $crate::json::object!{$($tokens)*};
-            $crate::process_css(Some($namespace), &json, false)
+            let json = $crate::__object!{$($tokens)*};
+            $crate::process_css(Some($namespace), &json, &$crate::Config::compact())
         }
     };
 }
@@ -156,155 +322,739 @@ macro_rules! jss_ns {
 macro_rules! jss_ns_pretty {
     ($namespace: tt, $($tokens:tt)+) => {
         {
-            let json = $crate::json::object!($($tokens)*);
-            $crate::process_css(Some($namespace), &json, true)
+            let json = $crate::__object!($($tokens)*);
+            $crate::process_css(Some($namespace), &json, &$crate::Config::pretty())
         }
     };
 }
 
 /// process json to css transforming the selector
 /// if class name is specified
-pub fn process_css(namespace: Option<&str>, json: &json::JsonValue, use_indents: bool) -> String {
-    process_css_selector_map(0, namespace, json, use_indents)
+pub fn process_css(namespace: Option<&str>, json: &json::JsonValue, config: &Config) -> String {
+    let mut buffer = String::new();
+    write_css(&mut buffer, namespace, json, config).expect("must write to String");
+    buffer
+}
+
+/// Write the css generated from the json into a `fmt::Write` sink
+/// such as a `String` or a `fmt::Formatter`.
+/// ```rust
+/// use jss::{json, write_css, Config};
+///
+/// let mut html = String::from("<style>");
+/// write_css(&mut html, None, &json::object!{
+///     ".layer": { display: "block" },
+/// }, &Config::compact()).unwrap();
+/// html += "</style>";
+/// assert_eq!("<style>.layer{display:block;}</style>", html);
+/// ```
+pub fn write_css(
+    w: &mut impl fmt::Write,
+    namespace: Option<&str>,
+    json: &json::JsonValue,
+    config: &Config,
+) -> fmt::Result {
+    write_stylesheet(w, namespace, json, &Render::new(config))
+}
+
+/// process json to css like [`process_css`], together with the warnings found by the
+/// [`Lint`](lint::Lint) of the config
+/// ```rust
+/// use jss::{json, lint::Lint, Config};
+///
+/// let config = Config {
+///     lint: Lint {
+///         at_rules: true,
+///         ..Lint::default()
+///     },
+///     ..Config::compact()
+/// };
+/// let (css, warnings) = jss::process_css_with_warnings(None, &json::object!{
+///     "@mdia (min-width: 768px)": {
+///         ".layer": { display: "block" },
+///     },
+/// }, &config);
+/// assert_eq!("@mdia (min-width: 768px){.layer{display:block;}}", css);
+/// assert_eq!("unknown at-rule in selector: `@mdia (min-width: 768px)`", warnings[0].to_string());
+/// ```
+pub fn process_css_with_warnings(
+    namespace: Option<&str>,
+    json: &json::JsonValue,
+    config: &Config,
+) -> (String, Vec<lint::Warning>) {
+    let render = Render::new(config);
+    let mut buffer = String::new();
+    write_stylesheet(&mut buffer, namespace, json, &render).expect("must write to String");
+    (buffer, render.into_warnings())
+}
+
+/// write the css of the json with the leading and final newlines of the formatter
+fn write_stylesheet(
+    w: &mut impl fmt::Write,
+    namespace: Option<&str>,
+    json: &json::JsonValue,
+    config: &Render,
+) -> fmt::Result {
+    if config.formatter.leading_newline {
+        w.write_str(config.formatter.newline())?;
+    }
+    write_css_selector_map(w, 0, namespace, json, config)?;
+    if config.formatter.final_newline {
+        w.write_str(config.formatter.newline())?;
+    }
+    Ok(())
+}
+
+/// Write the css generated from the json into an `io::Write` sink
+/// such as a file, a socket or a response body.
+/// ```rust
+/// use jss::{json, write_css_io, Config};
+///
+/// let mut response: Vec<u8> = vec![];
+/// write_css_io(&mut response, Some("frame"), &json::object!{
+///     ".layer": { display: "block" },
+/// }, &Config::compact()).unwrap();
+/// assert_eq!(b".frame__layer{display:block;}", response.as_slice());
+/// ```
+pub fn write_css_io(
+    w: &mut impl io::Write,
+    namespace: Option<&str>,
+    json: &json::JsonValue,
+    config: &Config,
+) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: None,
+    };
+    match write_css(&mut adapter, namespace, json, config) {
+        Ok(()) => Ok(()),
+        Err(fmt::Error) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatter error"))),
+    }
+}
+
+/// forwards the `fmt::Write` calls into an `io::Write`,
+/// keeping the underlying io error since `fmt::Error` can not carry it
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
 }
 
 /// This assumes that the key objects in json are selectors and the value is an object with the
 /// style names and their corresponding values
-fn process_css_selector_map(
+pub(crate) fn write_css_selector_map(
+    w: &mut impl fmt::Write,
     indent: usize,
     namespace: Option<&str>,
     css_map: &json::JsonValue,
-    use_indents: bool,
-) -> String {
-    let mut buffer = String::new();
-    for (classes, style_properties) in css_map.entries() {
-        if use_indents {
-            buffer += "\n";
-        }
-        if let Some(namespace) = &namespace {
-            buffer += &format!(
-                "{}{}",
-                make_indent(indent, use_indents),
-                selector_namespaced(namespace.to_string(), classes)
-            );
-        } else {
-            buffer += &format!("{}{}", make_indent(indent, use_indents), classes);
-        }
-        if use_indents {
-            buffer += " ";
-        }
-        buffer += "{";
-        if use_indents {
-            buffer += "\n";
+    config: &Render,
+) -> fmt::Result {
+    for (i, (classes, style_properties)) in css_map.entries().enumerate() {
+        if i > 0 {
+            w.write_str(config.formatter.newline())?;
+            if config.formatter.blank_line_between_rules {
+                w.write_str(config.formatter.newline())?;
+            }
         }
-        buffer += &process_css_properties(
+        write_css_rule(
+            w,
             indent,
             namespace,
-            Some(classes),
+            classes,
             style_properties,
-            use_indents,
+            false,
+            config,
+        )?;
+    }
+    Ok(())
+}
+
+/// write a single selector together with its block of style properties,
+/// `in_keyframe` is set when the selector is a keyframe such as `from` or `50%` of `@keyframes`
+fn write_css_rule(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    classes: &str,
+    style_properties: &json::JsonValue,
+    in_keyframe: bool,
+    config: &Render,
+) -> fmt::Result {
+    // the named breakpoints such as `@md` are expanded into their `@media` query
+    let breakpoint = config.breakpoints.resolve(classes);
+    let classes = breakpoint.as_deref().unwrap_or(classes);
+    // the container names are namespaced the same way as the classes
+    let container =
+        namespace.and_then(|namespace| container::selector_namespaced(namespace, classes));
+    let classes = container.as_deref().unwrap_or(classes);
+    let supports =
+        namespace.and_then(|namespace| supports::selector_namespaced(namespace, classes));
+    let classes = supports.as_deref().unwrap_or(classes);
+    if is_at_rule(classes) && config.lint.at_rules {
+        config.warn(
+            config
+                .lint
+                .check_at_rule(&rendered_selector(namespace, classes)),
         );
-        buffer += &make_indent(indent, use_indents);
-        buffer += "}";
     }
-    if use_indents {
-        buffer += "\n";
+    if is_at_rule(classes) && !style_properties.is_object() && !is_block_list(style_properties) {
+        return write_statement(w, indent, classes, style_properties, config);
     }
-    buffer
+    // the browsers which doesn't support `:has()` drop the whole rule,
+    // so the rule is written in a `@supports` block
+    if config.supports_fallback && !is_at_rule(classes) {
+        if let Some(query) = supports::has_selector(classes) {
+            let selector = query.selector();
+            let selector = namespace
+                .and_then(|namespace| supports::selector_namespaced(namespace, &selector))
+                .unwrap_or(selector);
+            config.formatter.write_indent(w, indent)?;
+            w.write_str(&selector)?;
+            w.write_str(config.formatter.brace_open())?;
+            w.write_str(config.formatter.newline())?;
+            config.formatter.write_nested_padding(w)?;
+            write_css_block(
+                w,
+                indent + 1,
+                namespace,
+                classes,
+                style_properties,
+                in_keyframe,
+                config,
+            )?;
+            w.write_str(config.formatter.newline())?;
+            config.formatter.write_nested_padding(w)?;
+            config.formatter.write_indent(w, indent)?;
+            return w.write_str("}");
+        }
+    }
+    write_css_block(
+        w,
+        indent,
+        namespace,
+        classes,
+        style_properties,
+        in_keyframe,
+        config,
+    )
+}
+
+/// write the block of the rule, followed by the rules hoisted out of it
+fn write_css_block(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    classes: &str,
+    style_properties: &json::JsonValue,
+    in_keyframe: bool,
+    config: &Render,
+) -> fmt::Result {
+    if is_block_list(style_properties) {
+        for (i, block) in style_properties.members().enumerate() {
+            if i > 0 {
+                w.write_str(config.formatter.newline())?;
+                if config.formatter.blank_line_between_rules {
+                    w.write_str(config.formatter.newline())?;
+                }
+            }
+            write_css_block(w, indent, namespace, classes, block, in_keyframe, config)?;
+        }
+        return Ok(());
+    }
+    write_rule_selector(w, indent, namespace, classes, config)?;
+    write_properties(
+        w,
+        indent,
+        namespace,
+        Some(classes),
+        style_properties,
+        in_keyframe,
+        config,
+    )?;
+    config.formatter.write_indent(w, indent)?;
+    w.write_str("}")?;
+    // `@keyframes` can not be nested in a style rule, so it is written after the rule
+    if !is_at_rule(classes) {
+        for (prop, value) in style_properties.entries() {
+            if is_keyframes(prop) && value.is_object() {
+                w.write_str(config.formatter.newline())?;
+                if config.formatter.blank_line_between_rules {
+                    w.write_str(config.formatter.newline())?;
+                }
+                write_css_rule(w, indent, namespace, prop, value, false, config)?;
+            }
+        }
+        // the responsive values are written in a `@media` block for each breakpoint,
+        // in the order of the breakpoints.
+        // A keyframe can not have a `@media` block, only the base of its responsive values is written
+        let breakpoints = if in_keyframe {
+            None
+        } else {
+            Some(config.breakpoints.iter())
+        };
+        for (breakpoint, query) in breakpoints.into_iter().flatten() {
+            let mut properties = json::JsonValue::new_object();
+            for (prop, value) in style_properties.entries() {
+                if is_responsive(value, config) && value.has_key(breakpoint) {
+                    properties[prop] = value[breakpoint].clone();
+                }
+            }
+            if properties.is_empty() {
+                continue;
+            }
+            let mut block = json::JsonValue::new_object();
+            block[classes] = properties;
+            w.write_str(config.formatter.newline())?;
+            if config.formatter.blank_line_between_rules {
+                w.write_str(config.formatter.newline())?;
+            }
+            write_css_rule(
+                w,
+                indent,
+                namespace,
+                &query.selector(),
+                &block,
+                false,
+                config,
+            )?;
+        }
+        // the declarations which needs the support are written in a `@supports` block,
+        // the rule only keeps their fallback.
+        // A keyframe can not have a `@supports` block, its declarations are kept in the keyframe
+        if config.supports_fallback && !in_keyframe {
+            for (prop, value) in style_properties.entries() {
+                let value = if is_responsive(value, config) {
+                    match value.entries().find(|(breakpoint, _)| *breakpoint == BASE) {
+                        Some((_, base)) => base,
+                        None => continue,
+                    }
+                } else {
+                    value
+                };
+                let style_name = style::resolve_name(prop, Some(classes))
+                    .or_else(|| style::any_name(prop))
+                    .unwrap_or(prop);
+                let value = if supports::is_fallback_map(value) {
+                    &value[supports::SUPPORTS]
+                } else if !value.is_object() && !is_at_rule(prop) && supports::is_modern(style_name)
+                {
+                    value
+                } else {
+                    continue;
+                };
+                let query = supports::declaration(style_name, JsonScalar(value));
+                w.write_str(config.formatter.newline())?;
+                if config.formatter.blank_line_between_rules {
+                    w.write_str(config.formatter.newline())?;
+                }
+                config.formatter.write_indent(w, indent)?;
+                w.write_str(&query.selector())?;
+                w.write_str(config.formatter.brace_open())?;
+                w.write_str(config.formatter.newline())?;
+                config.formatter.write_nested_padding(w)?;
+                write_rule_selector(w, indent + 1, namespace, classes, config)?;
+                write_json_declaration(w, indent + 2, namespace, style_name, value, config)?;
+                config.formatter.write_indent(w, indent + 1)?;
+                w.write_str("}")?;
+                w.write_str(config.formatter.newline())?;
+                config.formatter.write_nested_padding(w)?;
+                config.formatter.write_indent(w, indent)?;
+                w.write_str("}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// write the selector of the rule and the opening brace of its block,
+/// the classes of the selector are namespaced
+fn write_rule_selector(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    classes: &str,
+    config: &Render,
+) -> fmt::Result {
+    config.formatter.write_indent(w, indent)?;
+    if let Some(namespace) = &namespace {
+        config
+            .formatter
+            .write_selector(w, indent, &selector_namespaced(namespace, classes))?;
+    } else {
+        config.formatter.write_selector(w, indent, classes)?;
+    }
+    w.write_str(config.formatter.brace_open())?;
+    w.write_str(config.formatter.newline())
+}
+
+/// write an at-rule which has no block, the value is written after the at-rule name,
+/// ie: `"@layer": ["reset", "base"]` is `@layer reset, base;`
+pub(crate) fn write_statement(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    rule: &str,
+    value: &json::JsonValue,
+    config: &Render,
+) -> fmt::Result {
+    config.formatter.write_indent(w, indent)?;
+    w.write_str(rule.trim())?;
+    match value {
+        json::JsonValue::Null => (),
+        json::JsonValue::Array(items) => write!(w, " {}", JsonList(items, Separator::Comma))?,
+        value => match value.as_str() {
+            Some("") => (),
+            Some(s) => write!(w, " {}", s)?,
+            None => write!(w, " {}", value)?,
+        },
+    }
+    w.write_str(";")
+}
+
+fn is_at_rule(selector: &str) -> bool {
+    selector.trim_start().starts_with('@')
+}
+
+fn is_keyframes(selector: &str) -> bool {
+    selector.trim_start().starts_with("@keyframes")
+}
+
+/// an array of blocks writes the rule once for each of the blocks,
+/// ie: `"@font-face": [{ .. }, { .. }]` for the font faces of a family
+fn is_block_list(value: &json::JsonValue) -> bool {
+    value.is_array() && !value.is_empty() && value.members().all(json::JsonValue::is_object)
+}
+
+/// the key of the value which applies to every viewport in a responsive value
+const BASE: &str = "base";
+
+/// a responsive value is an object of the values for each breakpoint,
+/// ie: `{ base: px(8), md: px(16), lg: px(24) }`
+fn is_responsive(value: &json::JsonValue, config: &Render) -> bool {
+    value.is_object()
+        && !value.is_empty()
+        && value.entries().all(|(breakpoint, value)| {
+            (breakpoint == BASE || config.breakpoints.get(breakpoint).is_some())
+                && !value.is_object()
+        })
 }
 
 /// This process the values used inside a css selector
 pub fn process_css_properties(
     indent: usize,
     namespace: Option<&str>,
-    _classes: Option<&str>,
+    classes: Option<&str>,
     style_properties: &json::JsonValue,
-    use_indents: bool,
+    config: &Config,
 ) -> String {
     let mut buffer = String::new();
+    write_css_properties(
+        &mut buffer,
+        indent,
+        namespace,
+        classes,
+        style_properties,
+        config,
+    )
+    .expect("must write to String");
+    buffer
+}
+
+/// Write the values used inside a css selector into a `fmt::Write` sink
+pub fn write_css_properties(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    classes: Option<&str>,
+    style_properties: &json::JsonValue,
+    config: &Config,
+) -> fmt::Result {
+    write_properties(
+        w,
+        indent,
+        namespace,
+        classes,
+        style_properties,
+        false,
+        &Render::new(config),
+    )
+}
+
+/// write the values used inside a css selector,
+/// `in_keyframe` is set when the selector is a keyframe of `@keyframes`
+fn write_properties(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    classes: Option<&str>,
+    style_properties: &json::JsonValue,
+    in_keyframe: bool,
+    config: &Render,
+) -> fmt::Result {
+    let mut has_nested_rule = false;
+    let mut color = None;
+    let mut background_color = None;
+
+    let in_keyframes = classes.is_some_and(is_keyframes);
+    let in_style_rule = classes.is_some_and(|classes| !is_at_rule(classes));
+    if in_keyframes && config.lint.animatable {
+        config.warn(config.lint.check_keyframes(
+            &rendered_selector(namespace, classes.unwrap_or_default()),
+            style_properties,
+        ));
+    }
+    if in_keyframes && config.lint.responsive {
+        for (offset, properties) in style_properties.entries() {
+            for (prop, value) in properties.entries() {
+                if is_responsive(value, config) {
+                    config.warn(config.lint.check_responsive_keyframe(
+                        &rendered_selector(namespace, classes.unwrap_or_default()),
+                        prop,
+                        offset,
+                    ));
+                }
+            }
+        }
+    }
 
     for (prop, value) in style_properties.entries() {
-        if value.is_object() {
-            // recursive call to process_css_selector_map to support multiple layer of json object used in
+        let value = if (in_style_rule || in_keyframe) && is_responsive(value, config) {
+            // the values of the breakpoints are written after the style rule by `write_css_rule`,
+            // a keyframe can not have a `@media` block so only the base value is written there,
+            // reported by `Lint::responsive`
+            match value.entries().find(|(breakpoint, _)| *breakpoint == BASE) {
+                Some((_, base)) => base,
+                None => continue,
+            }
+        } else {
+            value
+        };
+        if (value.is_object() && !supports::is_fallback_map(value))
+            || is_block_list(value)
+            || is_at_rule(prop)
+        {
+            if in_style_rule && is_keyframes(prop) {
+                // written after the style rule by `write_css_rule`
+                continue;
+            }
+            // nested rule, to support multiple layer of json object used in
             // complex css such as animation and media queries
-            buffer +=
-                &process_css_selector_map(indent + 1, namespace, style_properties, use_indents);
-            if use_indents {
-                buffer += "\n";
+            if !has_nested_rule {
+                config.formatter.write_nested_padding(w)?;
+            } else if config.formatter.blank_line_between_rules {
+                w.write_str(config.formatter.newline())?;
             }
+            // the keyframe selectors such as `from` and `50%` are not namespaced
+            let namespace = if in_keyframes { None } else { namespace };
+            write_css_rule(w, indent + 1, namespace, prop, value, in_keyframes, config)?;
+            w.write_str(config.formatter.newline())?;
+            has_nested_rule = true;
         } else {
-            let style_name = if let Some(style_name) = style::from_ident(prop) {
-                style_name
-            } else {
-                let matched_property = style::match_name(prop);
-                if let Some(matched_property) = matched_property {
-                    matched_property
-                } else {
-                    // if strict, do a panic
-                    #[cfg(feature = "strict")]
-                    {
-                        panic!(
-                            "invalid style name: `{}` {}",
-                            prop,
-                            if let Some(classes) = _classes {
-                                format!("in selector: `{}`", classes)
-                            } else {
-                                "".to_string()
-                            }
-                        );
-                    }
-                    // if not strict return the prop as is
-                    #[cfg(not(feature = "strict"))]
-                    {
-                        prop
-                    }
+            let style_name = resolve_style_name(prop, namespace, classes, config);
+            match style_name {
+                "color" => color = value.as_str(),
+                "background-color" => background_color = value.as_str(),
+                _ => (),
+            }
+            // the declarations which needs the support are written after the style rule
+            // by `write_css_rule`
+            let hoisted = in_style_rule && !in_keyframe && config.supports_fallback;
+            if supports::is_fallback_map(value) {
+                let fallback = &value[supports::FALLBACK];
+                if !fallback.is_null() {
+                    write_json_declaration(w, indent + 1, namespace, style_name, fallback, config)?;
                 }
-            };
-            let value_str = match value {
-                json::JsonValue::String(s) => s.to_string(),
-                json::JsonValue::Short(s) => s.to_string(),
-                json::JsonValue::Number(v) => v.to_string(),
-                json::JsonValue::Boolean(v) => v.to_string(),
-                _ => {
-                    panic!(
-                        "supported values are String, Number or Bool only, found: {:?}",
-                        value
-                    )
+                if !hoisted {
+                    let supported = &value[supports::SUPPORTS];
+                    write_json_declaration(
+                        w,
+                        indent + 1,
+                        namespace,
+                        style_name,
+                        supported,
+                        config,
+                    )?;
                 }
-            };
-            if use_indents {
-                buffer += &format!(
-                    "{}{}: {};",
-                    make_indent(indent + 1, use_indents),
-                    style_name,
-                    value_str
-                );
-            } else {
-                buffer += &format!(
-                    "{}{}:{};",
-                    make_indent(indent + 1, use_indents),
-                    style_name,
-                    value_str
-                );
+            } else if !(hoisted && supports::is_modern(style_name)) {
+                write_json_declaration(w, indent + 1, namespace, style_name, value, config)?;
             }
-            if use_indents {
-                buffer += "\n";
+        }
+    }
+
+    if has_nested_rule {
+        config.formatter.write_nested_padding(w)?;
+    }
+
+    if let (Some(color), Some(background_color)) = (color, background_color) {
+        if config.lint.contrast.is_some() {
+            config.warn(config.lint.check_contrast(
+                &rendered_selector(namespace, classes.unwrap_or_default()),
+                color,
+                background_color,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// return the name of the property or the descriptor which is used in the rule of the classes
+fn resolve_style_name<'a>(
+    prop: &'a str,
+    namespace: Option<&str>,
+    classes: Option<&str>,
+    config: &Render,
+) -> &'a str {
+    if let Some(style_name) = style::resolve_name(prop, classes) {
+        return style_name;
+    }
+    // the name is unknown or it can not be used in this rule,
+    // ie: a descriptor of `@property` in a style rule
+    let any_name = style::any_name(prop);
+    if let (Some(classes), Some(any_name)) = (classes, any_name) {
+        if config.lint.descriptors {
+            config.warn(
+                config
+                    .lint
+                    .check_declaration(&rendered_selector(namespace, classes), any_name),
+            );
+        }
+    }
+    // if strict, do a panic
+    #[cfg(feature = "strict")]
+    {
+        panic!(
+            "invalid style name: `{}` {}",
+            prop,
+            if let Some(classes) = classes {
+                format!("in selector: `{}`", classes)
+            } else {
+                "".to_string()
             }
+        );
+    }
+    // if not strict return the name as is
+    #[cfg(not(feature = "strict"))]
+    {
+        any_name.unwrap_or(prop)
+    }
+}
+
+/// write the declaration of a value in the json
+fn write_json_declaration(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    namespace: Option<&str>,
+    style_name: &str,
+    value: &json::JsonValue,
+    config: &Render,
+) -> fmt::Result {
+    // the container names are namespaced the same way as the classes
+    let container_name;
+    let value = match (namespace, style_name, value.as_str()) {
+        (Some(namespace), "container" | "container-name", Some(names)) => {
+            container_name = json::JsonValue::from(container::value_namespaced(namespace, names));
+            &container_name
+        }
+        _ => value,
+    };
+    if config.color_fallback {
+        if let Some(fallback) = value.as_str().and_then(color::fallback) {
+            write_declaration(w, indent, style_name, &fallback, config)?;
+        }
+    }
+    match value {
+        json::JsonValue::String(_)
+        | json::JsonValue::Short(_)
+        | json::JsonValue::Number(_)
+        | json::JsonValue::Boolean(_)
+        | json::JsonValue::Array(_) => {
+            write_declaration(w, indent, style_name, &JsonScalar(value), config)
+        }
+        _ => {
+            panic!(
+                "supported values are String, Number, Bool or Array only, found: {:?}",
+                value
+            )
         }
     }
+}
 
-    buffer
+/// a value in the json written as the value of a declaration
+struct JsonScalar<'a>(&'a json::JsonValue);
+
+impl fmt::Display for JsonScalar<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            json::JsonValue::String(s) => f.write_str(s),
+            json::JsonValue::Short(s) => f.write_str(s),
+            json::JsonValue::Array(items) => write!(f, "{}", JsonList(items, Separator::Comma)),
+            value => write!(f, "{}", value),
+        }
+    }
 }
 
-/// convenient function to create indent
-fn make_indent(n: usize, use_indents: bool) -> String {
-    if use_indents {
-        "    ".repeat(n)
-    } else {
-        String::from("")
+/// the selector as it is written in the css, the selector in the lint warnings
+fn rendered_selector(namespace: Option<&str>, classes: &str) -> String {
+    match namespace {
+        Some(namespace) => selector_namespaced(namespace, classes),
+        None => classes.to_string(),
     }
 }
 
+/// an array in the json used as a value, the outer array is comma separated
+/// while the nested arrays are space separated,
+/// ie: `[["opacity", ms(200)], ["transform", ms(300)]]` is `opacity 200ms, transform 300ms`
+struct JsonList<'a>(&'a [json::JsonValue], Separator);
+
+impl fmt::Display for JsonList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let JsonList(items, separator) = self;
+        let list = List::new(
+            *separator,
+            items.iter().map(|item| match item {
+                json::JsonValue::Array(nested) => {
+                    Value::String(JsonList(nested, Separator::Space).to_string())
+                }
+                json::JsonValue::String(v) => Value::String(v.clone()),
+                json::JsonValue::Short(v) => Value::String(v.to_string()),
+                json::JsonValue::Number(v) => Value::String(v.to_string()),
+                json::JsonValue::Boolean(v) => Value::Bool(*v),
+                _ => panic!(
+                    "supported list items are String, Number, Bool or Array only, found: {:?}",
+                    item
+                ),
+            }),
+        );
+        write!(f, "{}", list)
+    }
+}
+
+/// write a single `name: value;` declaration in its own line
+fn write_declaration(
+    w: &mut impl fmt::Write,
+    indent: usize,
+    style_name: &str,
+    value: &impl fmt::Display,
+    config: &Render,
+) -> fmt::Result {
+    config.formatter.write_indent(w, indent)?;
+    w.write_str(style_name)?;
+    w.write_str(config.formatter.colon())?;
+    write!(w, "{}", value)?;
+    w.write_str(";")?;
+    w.write_str(config.formatter.newline())
+}
+
 /// Prepend a namespace to the selector classes,
 /// It does not affect other selectors such element selector, #id selector
 /// example:
@@ -350,7 +1100,7 @@ pub fn selector_namespaced(namespace: impl ToString, selector_classes: impl ToSt
                         .collect::<Vec<_>>()
                         .join(",")
                 } else {
-                    format!("{}", part)
+                    part.to_string()
                 }
             })
             .collect::<Vec<_>>()
@@ -0,0 +1,236 @@
+//! provides checks which run while the css is being rendered
+
+use crate::color::Color;
+use crate::style;
+use std::fmt;
+
+/// The WCAG conformance levels for the contrast of text
+/// [Reference](https://www.w3.org/TR/WCAG21/#contrast-minimum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// level AA for normal text, requires a contrast ratio of at least 4.5:1
+    Aa,
+    /// level AA for large text, requires a contrast ratio of at least 3:1
+    AaLarge,
+    /// level AAA for normal text, requires a contrast ratio of at least 7:1
+    Aaa,
+    /// level AAA for large text, requires a contrast ratio of at least 4.5:1
+    AaaLarge,
+}
+
+impl WcagLevel {
+    /// the minimum contrast ratio required by this level
+    pub fn min_ratio(&self) -> f64 {
+        match self {
+            WcagLevel::Aa => 4.5,
+            WcagLevel::AaLarge => 3.0,
+            WcagLevel::Aaa => 7.0,
+            WcagLevel::AaaLarge => 4.5,
+        }
+    }
+}
+
+/// A problem found by a lint
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    /// the selector of the rule where the problem is found
+    pub selector: String,
+    /// description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in selector: `{}`", self.message, self.selector)
+    }
+}
+
+/// The lints to run while rendering the css, this is part of the [`Config`](crate::Config).
+/// The warnings are returned by [`process_css_with_warnings`](crate::process_css_with_warnings),
+/// the other render functions discard them.
+/// ```rust
+/// use jss::{Config, lint::{Lint, WcagLevel}};
+///
+/// let config = Config {
+///     lint: Lint {
+///         contrast: Some(WcagLevel::Aa),
+///         ..Lint::default()
+///     },
+///     ..Config::compact()
+/// };
+/// let (css, warnings) = jss::process_css_with_warnings(None, &jss::json::object!{
+///     ".layer": {
+///         color: "white",
+///         background_color: "#777",
+///     }
+/// }, &config);
+/// assert_eq!(".layer{color:white;background-color:#777;}", css);
+/// assert_eq!(1, warnings.len());
+/// assert_eq!(".layer", warnings[0].selector);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Lint {
+    /// report the rules where `color` and `background-color` are both literal colors
+    /// which has lower contrast than required by the WCAG level
+    pub contrast: Option<WcagLevel>,
+    /// report the properties in `@keyframes` which can not be animated
+    pub animatable: bool,
+    /// report the descriptors used outside of their at-rule
+    /// and the properties used in an at-rule which only has descriptors
+    pub descriptors: bool,
+    /// report the at-rules which are unknown, such as a misspelled name
+    pub at_rules: bool,
+    /// report the responsive values in `@keyframes`, where only the `base` value is written
+    /// since a keyframe can not have a `@media` block
+    pub responsive: bool,
+}
+
+/// the properties which are ignored when used in `@keyframes`,
+/// `animation-timing-function` is allowed since it sets the easing of each keyframe
+/// [Reference](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_animated_properties)
+const NOT_ANIMATABLE: &[&str] = &[
+    "animation",
+    "animation-composition",
+    "animation-delay",
+    "animation-direction",
+    "animation-duration",
+    "animation-fill-mode",
+    "animation-iteration-count",
+    "animation-name",
+    "animation-play-state",
+    "animation-timeline",
+    "contain",
+    "container",
+    "container-name",
+    "container-type",
+    "direction",
+    "transition",
+    "transition-behavior",
+    "transition-delay",
+    "transition-duration",
+    "transition-property",
+    "transition-timing-function",
+    "unicode-bidi",
+    "will-change",
+];
+
+impl Lint {
+    /// report the properties in the keyframes which can not be animated
+    pub(crate) fn check_keyframes(
+        &self,
+        selector: &str,
+        keyframes: &json::JsonValue,
+    ) -> Vec<Warning> {
+        let mut warnings = vec![];
+        if !self.animatable {
+            return warnings;
+        }
+        for (offset, properties) in keyframes.entries() {
+            for (prop, _) in properties.entries() {
+                let name = style::from_ident(prop).unwrap_or(prop);
+                if NOT_ANIMATABLE.contains(&name) {
+                    warnings.push(Warning {
+                        selector: selector.to_string(),
+                        message: format!(
+                            "property `{}` at keyframe `{}` can not be animated",
+                            name, offset
+                        ),
+                    });
+                }
+            }
+        }
+        warnings
+    }
+
+    /// report the responsive value of the property at the keyframe, which is written without its breakpoints
+    pub(crate) fn check_responsive_keyframe(
+        &self,
+        selector: &str,
+        prop: &str,
+        offset: &str,
+    ) -> Option<Warning> {
+        if !self.responsive {
+            return None;
+        }
+        let name = style::from_ident(prop).unwrap_or(prop);
+        Some(Warning {
+            selector: selector.to_string(),
+            message: format!(
+                "responsive value of `{}` at keyframe `{}` can not be expanded into `@media` blocks",
+                name, offset
+            ),
+        })
+    }
+
+    /// report the declaration which can not be used in the rule,
+    /// a descriptor of another at-rule or a property in an at-rule which only has descriptors
+    pub(crate) fn check_declaration(&self, selector: &str, name: &str) -> Option<Warning> {
+        if !self.descriptors {
+            return None;
+        }
+        let at_rules = style::at_rules_of_descriptor(name);
+        let message = if !at_rules.is_empty() {
+            format!(
+                "descriptor `{}` can only be used in `{}`",
+                name,
+                at_rules.join("`, `")
+            )
+        } else if let Some(at_rule) = style::descriptors_only(selector) {
+            format!("`{}` is not a descriptor of `{}`", name, at_rule)
+        } else {
+            return None;
+        };
+        Some(Warning {
+            selector: selector.to_string(),
+            message,
+        })
+    }
+
+    /// report the at-rules which are unknown, such as a misspelled name
+    pub(crate) fn check_at_rule(&self, selector: &str) -> Option<Warning> {
+        if !self.at_rules || style::is_known_at_rule(selector) {
+            return None;
+        }
+        Some(Warning {
+            selector: selector.to_string(),
+            message: "unknown at-rule".to_string(),
+        })
+    }
+
+    /// check the contrast of the `color` and `background-color` values of a rule,
+    /// the values which are not literal colors such as `var(--fg)` or `inherit` are skipped
+    pub(crate) fn check_contrast(
+        &self,
+        selector: &str,
+        color: &str,
+        background_color: &str,
+    ) -> Option<Warning> {
+        let level = self.contrast?;
+        let (Ok(color), Ok(background_color)) =
+            (color.parse::<Color>(), background_color.parse::<Color>())
+        else {
+            return None;
+        };
+        // the color of the page behind a translucent background is unknown
+        if background_color.alpha() < 1.0 {
+            return None;
+        }
+        let ratio = color
+            .over(&background_color)
+            .contrast_ratio(&background_color);
+        if ratio >= level.min_ratio() {
+            return None;
+        }
+        Some(Warning {
+                selector: selector.to_string(),
+                message: format!(
+                    "contrast ratio of color `{}` on background-color `{}` is {:.2}:1, {:?} requires at least {}:1",
+                    color,
+                    background_color,
+                    ratio,
+                    level,
+                    level.min_ratio()
+                ),
+        })
+    }
+}
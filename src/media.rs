@@ -0,0 +1,608 @@
+//! a builder for the `@media` at-rule and the named [`Breakpoints`] which can be used as `"@md"`
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::media::{prefers_reduced_motion, width, ReducedMotion};
+//!
+//! let tablet = MediaQuery::screen().and(width().between(px(600), px(900)));
+//! let still = MediaQuery::from(prefers_reduced_motion(ReducedMotion::Reduce));
+//!
+//! let css = jss::jss!(
+//!     [&tablet.selector()]: {
+//!         ".sidebar": { display: "none" },
+//!     },
+//!     [&still.selector()]: {
+//!         ".spinner": { animation: "none" },
+//!     },
+//!     "@md": {
+//!         ".sidebar": { display: "block" },
+//!     },
+//! );
+//! assert_eq!(
+//!     "@media screen and (600px <= width <= 900px){.sidebar{display:none;}}\
+//!      @media (prefers-reduced-motion: reduce){.spinner{animation:none;}}\
+//!      @media (width >= 768px){.sidebar{display:block;}}",
+//!     css
+//! );
+//! ```
+
+use crate::style;
+use crate::units::{px, LengthUnit, Quantity, ResolutionUnit, Unit};
+use crate::value::impl_str_eq;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Not;
+
+/// The type of device of a media query
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media#media_types)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    /// `all`, every device
+    All,
+    /// `screen`
+    Screen,
+    /// `print`, paged material and the print preview
+    Print,
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MediaType::All => write!(f, "all"),
+            MediaType::Screen => write!(f, "screen"),
+            MediaType::Print => write!(f, "print"),
+        }
+    }
+}
+
+/// declare the keyword values of the discrete media features
+macro_rules! declare_keywords {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident => $css:literal,
+            )*
+        }
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum $name {
+                $(
+                    $(#[$variant_attr])*
+                    $variant,
+                )*
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match self {
+                        $($name::$variant => write!(f, $css),)*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+declare_keywords! {
+    /// the value of `prefers-color-scheme`
+    ColorScheme {
+        /// the user prefers a light theme
+        Light => "light",
+        /// the user prefers a dark theme
+        Dark => "dark",
+    }
+    /// the value of `prefers-reduced-motion`
+    ReducedMotion {
+        /// the user has no preference
+        NoPreference => "no-preference",
+        /// the user asked to minimize the non-essential motion
+        Reduce => "reduce",
+    }
+    /// the value of `hover` and `any-hover`
+    Hover {
+        /// the input mechanism can not hover, ie: a touch screen
+        None => "none",
+        /// the input mechanism can hover, ie: a mouse
+        Hover => "hover",
+    }
+    /// the value of `pointer` and `any-pointer`
+    Pointer {
+        /// there is no pointing device
+        None => "none",
+        /// a pointing device of limited accuracy, ie: a finger
+        Coarse => "coarse",
+        /// an accurate pointing device, ie: a mouse
+        Fine => "fine",
+    }
+    /// the value of `orientation`
+    Orientation {
+        /// the viewport is taller than it is wide
+        Portrait => "portrait",
+        /// the viewport is wider than it is tall
+        Landscape => "landscape",
+    }
+}
+
+/// A media feature or a combination of them with `and`, `or` and `not` (`!`)
+/// ```rust
+/// use jss::prelude::*;
+/// use jss::media::{hover, pointer, width, Hover, Pointer};
+///
+/// let touch = !hover(Hover::Hover).or(pointer(Pointer::Coarse));
+/// assert_eq!("not ((hover: hover) or (pointer: coarse))", touch.to_string());
+///
+/// let narrow = width().less_than(px(600)).and(touch);
+/// assert_eq!(
+///     "(width < 600px) and (not ((hover: hover) or (pointer: coarse)))",
+///     narrow.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaCondition {
+    /// a single media feature including its parenthesis, ie: `(color)`
+    Feature(String),
+    /// the negation of the condition
+    Not(Box<MediaCondition>),
+    /// all of the conditions are true
+    And(Vec<MediaCondition>),
+    /// any of the conditions is true
+    Or(Vec<MediaCondition>),
+}
+
+impl MediaCondition {
+    /// both this and the other condition are true
+    pub fn and(self, other: MediaCondition) -> Self {
+        match self {
+            MediaCondition::And(mut conditions) => {
+                conditions.push(other);
+                MediaCondition::And(conditions)
+            }
+            this => MediaCondition::And(vec![this, other]),
+        }
+    }
+
+    /// either this or the other condition is true
+    pub fn or(self, other: MediaCondition) -> Self {
+        match self {
+            MediaCondition::Or(mut conditions) => {
+                conditions.push(other);
+                MediaCondition::Or(conditions)
+            }
+            this => MediaCondition::Or(vec![this, other]),
+        }
+    }
+
+    /// write the condition, wrapped in parenthesis unless it is a single feature
+    fn fmt_in_parens(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MediaCondition::Feature(feature) => write!(f, "{}", feature),
+            condition => write!(f, "({})", condition),
+        }
+    }
+}
+
+impl Not for MediaCondition {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        MediaCondition::Not(Box::new(self))
+    }
+}
+
+impl fmt::Display for MediaCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (conditions, operator) = match self {
+            MediaCondition::Feature(feature) => return write!(f, "{}", feature),
+            MediaCondition::Not(condition) => {
+                write!(f, "not ")?;
+                return condition.fmt_in_parens(f);
+            }
+            MediaCondition::And(conditions) => (conditions, " and "),
+            MediaCondition::Or(conditions) => (conditions, " or "),
+        };
+        for (i, condition) in conditions.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", operator)?;
+            }
+            condition.fmt_in_parens(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A media feature which is compared using the range syntax, ie: `(width >= 600px)`
+#[derive(Debug, Clone, Copy)]
+pub struct RangeFeature<U> {
+    name: &'static str,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> RangeFeature<U> {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            unit: PhantomData,
+        }
+    }
+
+    fn compare(&self, operator: &str, value: Quantity<U>) -> MediaCondition {
+        MediaCondition::Feature(format!("({} {} {})", self.name, operator, value))
+    }
+
+    /// `(name >= value)`
+    pub fn at_least(&self, value: Quantity<U>) -> MediaCondition {
+        self.compare(">=", value)
+    }
+
+    /// `(name <= value)`
+    pub fn at_most(&self, value: Quantity<U>) -> MediaCondition {
+        self.compare("<=", value)
+    }
+
+    /// `(name > value)`
+    pub fn greater_than(&self, value: Quantity<U>) -> MediaCondition {
+        self.compare(">", value)
+    }
+
+    /// `(name < value)`
+    pub fn less_than(&self, value: Quantity<U>) -> MediaCondition {
+        self.compare("<", value)
+    }
+
+    /// `(name: value)`
+    pub fn equals(&self, value: Quantity<U>) -> MediaCondition {
+        MediaCondition::Feature(format!("({}: {})", self.name, value))
+    }
+
+    /// `(min <= name <= max)`, both ends are inclusive
+    pub fn between(&self, min: Quantity<U>, max: Quantity<U>) -> MediaCondition {
+        MediaCondition::Feature(format!("({} <= {} <= {})", min, self.name, max))
+    }
+}
+
+/// the width of the viewport, or the page box for paged media
+pub fn width() -> RangeFeature<LengthUnit> {
+    RangeFeature::new("width")
+}
+
+/// the height of the viewport, or the page box for paged media
+pub fn height() -> RangeFeature<LengthUnit> {
+    RangeFeature::new("height")
+}
+
+/// the pixel density of the output device, ie: `resolution().at_least(dppx(2))`
+pub fn resolution() -> RangeFeature<ResolutionUnit> {
+    RangeFeature::new("resolution")
+}
+
+fn keyword(name: &str, value: impl fmt::Display) -> MediaCondition {
+    MediaCondition::Feature(format!("({}: {})", name, value))
+}
+
+/// whether the user prefers a light or dark theme
+pub fn prefers_color_scheme(scheme: ColorScheme) -> MediaCondition {
+    keyword("prefers-color-scheme", scheme)
+}
+
+/// whether the user asked to minimize the non-essential motion
+pub fn prefers_reduced_motion(motion: ReducedMotion) -> MediaCondition {
+    keyword("prefers-reduced-motion", motion)
+}
+
+/// whether the primary input mechanism can hover over elements
+pub fn hover(hover: Hover) -> MediaCondition {
+    keyword("hover", hover)
+}
+
+/// whether any of the input mechanisms can hover over elements
+pub fn any_hover(hover: Hover) -> MediaCondition {
+    keyword("any-hover", hover)
+}
+
+/// the accuracy of the primary pointing device
+pub fn pointer(pointer: Pointer) -> MediaCondition {
+    keyword("pointer", pointer)
+}
+
+/// the accuracy of any of the pointing devices
+pub fn any_pointer(pointer: Pointer) -> MediaCondition {
+    keyword("any-pointer", pointer)
+}
+
+/// the orientation of the viewport, or of the container in `@container`
+pub fn orientation(orientation: Orientation) -> MediaCondition {
+    keyword("orientation", orientation)
+}
+
+/// a single query of a [`MediaQuery`] list
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Query {
+    not: bool,
+    media_type: Option<MediaType>,
+    condition: Option<MediaCondition>,
+}
+
+/// A comma separated list of media queries, the styles applies when any of them matches.
+///
+/// `and` and `!` applies to the last query of the list, while `or` starts a new query.
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media)
+/// ```rust
+/// use jss::prelude::*;
+/// use jss::media::{orientation, width, Orientation};
+///
+/// let query = MediaQuery::print()
+///     .or(MediaQuery::screen().and(width().at_most(px(400))))
+///     .or(!MediaQuery::from(orientation(Orientation::Landscape)));
+/// assert_eq!(
+///     "@media print, screen and (width <= 400px), not (orientation: landscape)",
+///     query.selector()
+/// );
+/// assert_eq!("not screen", (!MediaQuery::screen()).to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaQuery {
+    queries: Vec<Query>,
+}
+
+impl MediaQuery {
+    /// a query which matches the media type
+    pub fn new(media_type: MediaType) -> Self {
+        Self {
+            queries: vec![Query {
+                not: false,
+                media_type: Some(media_type),
+                condition: None,
+            }],
+        }
+    }
+
+    /// `all`
+    pub fn all() -> Self {
+        Self::new(MediaType::All)
+    }
+
+    /// `screen`
+    pub fn screen() -> Self {
+        Self::new(MediaType::Screen)
+    }
+
+    /// `print`
+    pub fn print() -> Self {
+        Self::new(MediaType::Print)
+    }
+
+    /// the last query also needs to match the condition
+    pub fn and(mut self, condition: MediaCondition) -> Self {
+        let query = self.queries.last_mut().expect("must have a query");
+        query.condition = Some(match query.condition.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// the styles also applies when the other query matches
+    pub fn or(mut self, other: impl Into<MediaQuery>) -> Self {
+        self.queries.extend(other.into().queries);
+        self
+    }
+
+    /// the at-rule selector, `@media <queries>`
+    pub fn selector(&self) -> String {
+        format!("@media {}", self)
+    }
+}
+
+impl From<MediaCondition> for MediaQuery {
+    fn from(condition: MediaCondition) -> Self {
+        Self {
+            queries: vec![Query {
+                not: false,
+                media_type: None,
+                condition: Some(condition),
+            }],
+        }
+    }
+}
+
+impl Not for MediaQuery {
+    type Output = Self;
+
+    /// negate the last query of the list
+    fn not(mut self) -> Self {
+        let query = self.queries.last_mut().expect("must have a query");
+        if query.media_type.is_some() {
+            query.not = !query.not;
+        } else if let Some(condition) = query.condition.take() {
+            query.condition = Some(match condition {
+                MediaCondition::Not(condition) => *condition,
+                condition => !condition,
+            });
+        }
+        self
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.media_type, &self.condition) {
+            (Some(media_type), condition) => {
+                if self.not {
+                    write!(f, "not ")?;
+                }
+                write!(f, "{}", media_type)?;
+                match condition {
+                    // `or` can not be mixed with `and` without the parenthesis
+                    Some(condition @ MediaCondition::Or(_)) => {
+                        write!(f, " and ({})", condition)
+                    }
+                    Some(condition) => write!(f, " and {}", condition),
+                    None => Ok(()),
+                }
+            }
+            (None, Some(condition)) => write!(f, "{}", condition),
+            (None, None) => write!(f, "all"),
+        }
+    }
+}
+
+impl fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, query) in self.queries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", query)?;
+        }
+        Ok(())
+    }
+}
+
+impl_str_eq!(MediaQuery);
+
+/// The named media queries which can be used as an at-rule in the json, ie: `"@md"`,
+/// configured with the `breakpoints` field of the [`Config`](crate::Config).
+///
+/// The default breakpoints are mobile first:
+///
+/// | name | media query          |
+/// |------|----------------------|
+/// | `sm` | `(width >= 640px)`   |
+/// | `md` | `(width >= 768px)`   |
+/// | `lg` | `(width >= 1024px)`  |
+/// | `xl` | `(width >= 1280px)`  |
+///
+/// ```rust
+/// use jss::prelude::*;
+/// use jss::media::width;
+///
+/// let config = Config {
+///     breakpoints: Breakpoints::empty()
+///         .with("phone", width().less_than(px(600)))
+///         .and_then(|breakpoints| breakpoints.with("print", MediaQuery::print()))
+///         .unwrap(),
+///     ..Config::compact()
+/// };
+/// let css = jss::process_css(Some("nav"), &jss::json::object! {
+///     "@phone": {
+///         ".menu": { display: "none" },
+///     },
+/// }, &config);
+/// assert_eq!("@media (width < 600px){.nav__menu{display:none;}}", css);
+/// ```
+///
+/// A property can also have a responsive value with the value for each breakpoint,
+/// where `base` applies to every viewport.
+/// The breakpoint values are written in a `@media` block after the rule,
+/// in the order of the breakpoints.
+/// A keyframe can not have a `@media` block, so only the `base` of a responsive value is written
+/// in `@keyframes`, the dropped breakpoint values are reported by [`Lint::responsive`](crate::lint::Lint::responsive).
+/// ```rust
+/// use jss::prelude::*;
+///
+/// let css = jss::jss_ns!("card",
+///     ".": {
+///         padding: { base: px(8), md: px(16), lg: px(24) },
+///         gap: { lg: px(12) },
+///     },
+/// );
+/// assert_eq!(
+///     ".card{padding:8px;}\
+///      @media (width >= 768px){.card{padding:16px;}}\
+///      @media (width >= 1024px){.card{padding:24px;gap:12px;}}",
+///     css
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoints(Vec<(String, MediaQuery)>);
+
+/// The error when a breakpoint can not be added
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreakpointError {
+    /// the name is an at-rule, ie: `media` would make `"@media"` a breakpoint
+    AtRule(String),
+}
+
+impl fmt::Display for BreakpointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BreakpointError::AtRule(name) => {
+                write!(
+                    f,
+                    "breakpoint `{}` is the name of the at-rule `@{}`",
+                    name, name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BreakpointError {}
+
+impl Breakpoints {
+    /// no breakpoints at all, use [`Breakpoints::default`] for the default breakpoints
+    pub fn empty() -> Self {
+        Self(vec![])
+    }
+
+    /// add the named breakpoint, replacing the existing one with the same name.
+    /// The name can not be the name of an at-rule such as `media` or `page`
+    /// ```rust
+    /// use jss::media::{width, BreakpointError, Breakpoints};
+    /// use jss::units::px;
+    ///
+    /// assert_eq!(
+    ///     Err(BreakpointError::AtRule("page".to_string())),
+    ///     Breakpoints::default().with("page", width().at_least(px(600)))
+    /// );
+    /// ```
+    pub fn with(
+        mut self,
+        name: impl ToString,
+        query: impl Into<MediaQuery>,
+    ) -> Result<Self, BreakpointError> {
+        let name = name.to_string();
+        if style::is_known_at_rule(&format!("@{}", name)) {
+            return Err(BreakpointError::AtRule(name));
+        }
+        let query = query.into();
+        match self.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = query,
+            None => self.0.push((name, query)),
+        }
+        Ok(self)
+    }
+
+    /// the media query of the named breakpoint
+    pub fn get(&self, name: &str) -> Option<&MediaQuery> {
+        self.0
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, query)| query)
+    }
+
+    /// the breakpoints in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MediaQuery)> {
+        self.0.iter().map(|(name, query)| (name.as_str(), query))
+    }
+
+    /// the `@media` selector when the selector is `@<name>` of a breakpoint
+    pub fn resolve(&self, selector: &str) -> Option<String> {
+        let name = selector.trim().strip_prefix('@')?;
+        self.get(name).map(MediaQuery::selector)
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self(vec![
+            ("sm".to_string(), width().at_least(px(640)).into()),
+            ("md".to_string(), width().at_least(px(768)).into()),
+            ("lg".to_string(), width().at_least(px(1024)).into()),
+            ("xl".to_string(), width().at_least(px(1280)).into()),
+        ])
+    }
+}
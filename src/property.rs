@@ -0,0 +1,314 @@
+//! a builder for the `@property` at-rule, the registration of a custom property with a type,
+//! so that it can be animated and transitioned
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::json::JsonValue;
+//!
+//! let angle = CustomProperty::new("angle", Syntax::Angle)
+//!     .inherits(false)
+//!     .initial_value(deg(0));
+//!
+//! let css = jss::jss!(
+//!     [&angle.selector()]: JsonValue::try_from(&angle).unwrap(),
+//!     ".spinner": {
+//!         [angle.name()]: deg(45),
+//!         rotate: angle.var(),
+//!         transition: [[angle.name(), ms(300)]],
+//!     },
+//! );
+//! assert_eq!(
+//!     r#"@property --angle{syntax:"<angle>";inherits:false;initial-value:0deg;}.spinner{--angle:45deg;rotate:var(--angle);transition:--angle 300ms;}"#,
+//!     css
+//! );
+//! ```
+
+use crate::color::Color;
+use crate::fns::{quoted, var, Var};
+use std::fmt;
+
+/// The type of the value of a registered custom property, the `syntax` descriptor
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@property/syntax)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    /// `*`, any value, the property can not be animated
+    Universal,
+    /// `<length>`, ie: `12px`
+    Length,
+    /// `<percentage>`, ie: `50%`
+    Percentage,
+    /// `<length-percentage>`, either a length or a percentage
+    LengthPercentage,
+    /// `<number>`, ie: `1.5`
+    Number,
+    /// `<integer>`, ie: `3`
+    Integer,
+    /// `<angle>`, ie: `45deg`
+    Angle,
+    /// `<time>`, ie: `200ms`
+    Time,
+    /// `<resolution>`, ie: `2dppx`
+    Resolution,
+    /// `<color>`, ie: `#ff0000`
+    Color,
+    /// `<url>`, ie: `url("a.png")`
+    Url,
+    /// `<image>`, an url or a gradient
+    Image,
+    /// `<transform-function>`, ie: `rotate(45deg)`
+    TransformFunction,
+    /// `<custom-ident>`, ie: `compact`
+    CustomIdent,
+}
+
+impl fmt::Display for Syntax {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let syntax = match self {
+            Syntax::Universal => "*",
+            Syntax::Length => "<length>",
+            Syntax::Percentage => "<percentage>",
+            Syntax::LengthPercentage => "<length-percentage>",
+            Syntax::Number => "<number>",
+            Syntax::Integer => "<integer>",
+            Syntax::Angle => "<angle>",
+            Syntax::Time => "<time>",
+            Syntax::Resolution => "<resolution>",
+            Syntax::Color => "<color>",
+            Syntax::Url => "<url>",
+            Syntax::Image => "<image>",
+            Syntax::TransformFunction => "<transform-function>",
+            Syntax::CustomIdent => "<custom-ident>",
+        };
+        write!(f, "{}", syntax)
+    }
+}
+
+/// the units of the lengths which doesn't depend on the font or the viewport,
+/// the initial value has to be computationally independent
+const ABSOLUTE_LENGTH_UNITS: &[&str] = &["px", "cm", "mm", "q", "in", "pt", "pc"];
+const ANGLE_UNITS: &[&str] = &["deg", "grad", "rad", "turn"];
+const TIME_UNITS: &[&str] = &["s", "ms"];
+const RESOLUTION_UNITS: &[&str] = &["dpi", "dpcm", "dppx", "x"];
+/// [Reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function)
+const TRANSFORM_FUNCTIONS: &[&str] = &[
+    "matrix",
+    "matrix3d",
+    "perspective",
+    "rotate",
+    "rotate3d",
+    "rotateX",
+    "rotateY",
+    "rotateZ",
+    "scale",
+    "scale3d",
+    "scaleX",
+    "scaleY",
+    "scaleZ",
+    "skew",
+    "skewX",
+    "skewY",
+    "translate",
+    "translate3d",
+    "translateX",
+    "translateY",
+    "translateZ",
+];
+
+impl Syntax {
+    /// whether the value is of this type and can be used as the initial value
+    fn matches(&self, value: &str) -> bool {
+        let value = value.trim();
+        // the name of the css function of the value, ie: `url` in `url("a.png")`
+        let function = value
+            .strip_suffix(')')
+            .and_then(|value| value.split_once('('))
+            .map(|(name, _)| name)
+            .filter(|name| is_ident(name));
+        match self {
+            Syntax::Universal => true,
+            Syntax::Length => value == "0" || has_unit(value, ABSOLUTE_LENGTH_UNITS),
+            Syntax::Percentage => has_unit(value, &["%"]),
+            Syntax::LengthPercentage => {
+                Syntax::Length.matches(value) || Syntax::Percentage.matches(value)
+            }
+            Syntax::Number => value.parse::<f64>().is_ok_and(f64::is_finite),
+            Syntax::Integer => value.parse::<i64>().is_ok(),
+            Syntax::Angle => value == "0" || has_unit(value, ANGLE_UNITS),
+            Syntax::Time => has_unit(value, TIME_UNITS),
+            Syntax::Resolution => has_unit(value, RESOLUTION_UNITS),
+            Syntax::Color => value.parse::<Color>().is_ok(),
+            Syntax::Url => function == Some("url"),
+            Syntax::Image => function.is_some_and(|name| {
+                matches!(name, "url" | "image-set" | "cross-fade") || name.ends_with("-gradient")
+            }),
+            Syntax::TransformFunction => function.is_some_and(|name| {
+                TRANSFORM_FUNCTIONS
+                    .iter()
+                    .any(|function| function.eq_ignore_ascii_case(name))
+            }),
+            Syntax::CustomIdent => {
+                is_ident(value)
+                    && !matches!(
+                        value,
+                        "inherit" | "initial" | "unset" | "revert" | "revert-layer" | "default"
+                    )
+            }
+        }
+    }
+}
+
+/// a number followed by one of the units, ie: `12px`
+fn has_unit(value: &str, units: &[&str]) -> bool {
+    units.iter().any(|unit| {
+        value
+            .strip_suffix(unit)
+            .is_some_and(|number| number.parse::<f64>().is_ok_and(f64::is_finite))
+    })
+}
+
+fn is_ident(value: &str) -> bool {
+    !value.is_empty()
+        && !value.starts_with(|c: char| c.is_ascii_digit())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// The reason the registration of a custom property is not valid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomPropertyError {
+    /// there is no initial value, it is only optional for the universal syntax `*`
+    MissingInitialValue,
+    /// the initial value is not of the syntax or it depends on the font or the viewport
+    InvalidInitialValue {
+        /// the syntax of the property
+        syntax: Syntax,
+        /// the initial value
+        value: String,
+    },
+}
+
+impl fmt::Display for CustomPropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomPropertyError::MissingInitialValue => {
+                write!(f, "the custom property has no initial value")
+            }
+            CustomPropertyError::InvalidInitialValue { syntax, value } => write!(
+                f,
+                "initial value `{}` is not a computationally independent {}",
+                value, syntax
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CustomPropertyError {}
+
+/// A registered custom property, rendered as `@property`.
+///
+/// The registered type lets the browser interpolate the value of the property,
+/// use [`var`](CustomProperty::var) to refer to it in the values
+/// and [`name`](CustomProperty::name) to set it in a rule.
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomProperty {
+    name: String,
+    syntax: Syntax,
+    inherits: bool,
+    initial_value: Option<String>,
+}
+
+impl CustomProperty {
+    /// register the custom property with the type of its value,
+    /// the `--` prefix of the name is added when omitted.
+    /// The property inherits by default the same as the unregistered custom properties
+    pub fn new(name: impl fmt::Display, syntax: Syntax) -> Self {
+        let name = name.to_string();
+        let name = if name.starts_with("--") {
+            name
+        } else {
+            format!("--{}", name)
+        };
+        Self {
+            name,
+            syntax,
+            inherits: true,
+            initial_value: None,
+        }
+    }
+
+    /// whether the property inherits its value from the parent element
+    pub fn inherits(mut self, inherits: bool) -> Self {
+        self.inherits = inherits;
+        self
+    }
+
+    /// the value of the property when it is not set, it has to be of the syntax
+    pub fn initial_value(mut self, value: impl fmt::Display) -> Self {
+        self.initial_value = Some(value.to_string());
+        self
+    }
+
+    /// the name of the property including the `--` prefix, ie: `--angle`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// the type of the value of the property
+    pub fn syntax(&self) -> Syntax {
+        self.syntax
+    }
+
+    /// a reference to the property, ie: `var(--angle)`
+    pub fn var(&self) -> Var {
+        var(&self.name)
+    }
+
+    /// the at-rule selector, `@property <name>`
+    pub fn selector(&self) -> String {
+        format!("@property {}", self.name)
+    }
+
+    /// check that the initial value is of the syntax
+    pub fn validate(&self) -> Result<(), CustomPropertyError> {
+        match &self.initial_value {
+            None if self.syntax == Syntax::Universal => Ok(()),
+            None => Err(CustomPropertyError::MissingInitialValue),
+            Some(value) if self.syntax.matches(value) => Ok(()),
+            Some(value) => Err(CustomPropertyError::InvalidInitialValue {
+                syntax: self.syntax,
+                value: value.clone(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for CustomProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// the block of the at-rule, the descriptors together with their values
+impl TryFrom<&CustomProperty> for json::JsonValue {
+    type Error = CustomPropertyError;
+
+    fn try_from(property: &CustomProperty) -> Result<Self, CustomPropertyError> {
+        property.validate()?;
+        let mut block = json::JsonValue::new_object();
+        block["syntax"] = quoted(property.syntax).into();
+        block["inherits"] = property.inherits.into();
+        if let Some(initial_value) = &property.initial_value {
+            block["initial-value"] = initial_value.clone().into();
+        }
+        Ok(block)
+    }
+}
+
+impl TryFrom<CustomProperty> for json::JsonValue {
+    type Error = CustomPropertyError;
+
+    fn try_from(property: CustomProperty) -> Result<Self, CustomPropertyError> {
+        json::JsonValue::try_from(&property)
+    }
+}
@@ -0,0 +1,218 @@
+//! the shadows of `box-shadow`, `text-shadow` and `drop-shadow()`
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! let card = shadow(px(0), px(1))
+//!     .blur(px(3))
+//!     .color(Color::rgba(0, 0, 0, 0.12))
+//!     .and(shadow(px(0), px(1)).blur(px(2)).color(Color::rgba(0, 0, 0, 0.24)));
+//! assert_eq!(
+//!     "0px 1px 3px rgba(0, 0, 0, 0.12), 0px 1px 2px rgba(0, 0, 0, 0.24)",
+//!     card.to_string()
+//! );
+//!
+//! // elevation tokens generated from a numeric scale
+//! let elevation = |level: i32| {
+//!     shadow(px(0), px(level))
+//!         .blur(px(level * 2))
+//!         .color(Color::rgba(0, 0, 0, 0.2))
+//! };
+//! assert_eq!("0px 4px 8px rgba(0, 0, 0, 0.2)", elevation(4));
+//!
+//! let outline = text_shadow(px(1), px(1)).color("white").and(text_shadow(px(-1), px(-1)).color("white"));
+//! assert_eq!("1px 1px white, -1px -1px white", outline);
+//! ```
+
+use crate::units::Length;
+use crate::value::impl_css_value;
+use std::fmt;
+
+/// A single shadow of `box-shadow`, the components are written in the order of
+/// `inset? offset-x offset-y blur-radius? spread-radius? color?`.
+/// `text-shadow` and `drop-shadow()` doesn't support `inset` and the spread radius,
+/// these use a [`TextShadow`]
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/box-shadow)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shadow {
+    inset: bool,
+    offset_x: Length,
+    offset_y: Length,
+    blur: Option<Length>,
+    spread: Option<Length>,
+    color: Option<String>,
+}
+
+/// A single shadow of `text-shadow` and `drop-shadow()`, written as
+/// `offset-x offset-y blur-radius? color?`
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/text-shadow)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextShadow {
+    offset_x: Length,
+    offset_y: Length,
+    blur: Option<Length>,
+    color: Option<String>,
+}
+
+/// A comma separated list of shadows, the first shadow is on top.
+/// An empty list is written as `none`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Shadows(pub Vec<Shadow>);
+
+/// A comma separated list of text shadows, the first shadow is on top.
+/// An empty list is written as `none`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextShadows(pub Vec<TextShadow>);
+
+/// a shadow at the offset, without blur and in the current color unless specified
+pub fn shadow(offset_x: Length, offset_y: Length) -> Shadow {
+    Shadow {
+        inset: false,
+        offset_x,
+        offset_y,
+        blur: None,
+        spread: None,
+        color: None,
+    }
+}
+
+impl Shadow {
+    /// the blur radius, the larger the value the bigger and lighter the shadow
+    pub fn blur(mut self, blur: Length) -> Self {
+        self.blur = Some(blur);
+        self
+    }
+
+    /// the spread radius, positive values expands the shadow and negative values shrinks it
+    pub fn spread(mut self, spread: Length) -> Self {
+        self.spread = Some(spread);
+        self
+    }
+
+    /// the color of the shadow, ie: a `Color`, `ModernColor` or `var()`
+    pub fn color(mut self, color: impl fmt::Display) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// draw the shadow inside the border
+    pub fn inset(mut self) -> Self {
+        self.inset = true;
+        self
+    }
+
+    /// layer another shadow below this one
+    pub fn and(self, shadow: Shadow) -> Shadows {
+        Shadows(vec![self, shadow])
+    }
+}
+
+/// a text shadow at the offset, without blur and in the current color unless specified
+pub fn text_shadow(offset_x: Length, offset_y: Length) -> TextShadow {
+    TextShadow {
+        offset_x,
+        offset_y,
+        blur: None,
+        color: None,
+    }
+}
+
+impl TextShadow {
+    /// the blur radius, the larger the value the bigger and lighter the shadow
+    pub fn blur(mut self, blur: Length) -> Self {
+        self.blur = Some(blur);
+        self
+    }
+
+    /// the color of the shadow, ie: a `Color`, `ModernColor` or `var()`
+    pub fn color(mut self, color: impl fmt::Display) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// layer another shadow below this one
+    pub fn and(self, shadow: TextShadow) -> TextShadows {
+        TextShadows(vec![self, shadow])
+    }
+}
+
+impl fmt::Display for Shadow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.inset {
+            write!(f, "inset ")?;
+        }
+        write!(f, "{} {}", self.offset_x, self.offset_y)?;
+        match (&self.blur, &self.spread) {
+            (Some(blur), Some(spread)) => write!(f, " {} {}", blur, spread)?,
+            (Some(blur), None) => write!(f, " {}", blur)?,
+            // the blur radius is needed before the spread radius
+            (None, Some(spread)) => write!(f, " 0 {}", spread)?,
+            (None, None) => (),
+        }
+        if let Some(color) = &self.color {
+            write!(f, " {}", color)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TextShadow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.offset_x, self.offset_y)?;
+        if let Some(blur) = &self.blur {
+            write!(f, " {}", blur)?;
+        }
+        if let Some(color) = &self.color {
+            write!(f, " {}", color)?;
+        }
+        Ok(())
+    }
+}
+
+/// the layering, display and conversions shared by the lists of shadows
+macro_rules! impl_shadow_list {
+    ($($list:ident($shadow:ident);)*) => {
+        $(
+            impl $list {
+                /// layer another shadow below the shadows
+                pub fn and(mut self, shadow: $shadow) -> Self {
+                    self.0.push(shadow);
+                    self
+                }
+            }
+
+            impl fmt::Display for $list {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    if self.0.is_empty() {
+                        return write!(f, "none");
+                    }
+                    for (i, shadow) in self.0.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", shadow)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl From<$shadow> for $list {
+                fn from(shadow: $shadow) -> Self {
+                    Self(vec![shadow])
+                }
+            }
+
+            impl FromIterator<$shadow> for $list {
+                fn from_iter<I: IntoIterator<Item = $shadow>>(iter: I) -> Self {
+                    Self(iter.into_iter().collect())
+                }
+            }
+
+            impl_css_value!($shadow, $list);
+        )*
+    };
+}
+
+impl_shadow_list! {
+    Shadows(Shadow);
+    TextShadows(TextShadow);
+}
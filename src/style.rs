@@ -1,9 +1,11 @@
+use at_rule::{AtRule, AT_RULES, AT_RULE_DESCRIPTORS, FEATURE_VALUE_BLOCKS};
 use once_cell::sync::Lazy;
 use phf::phf_map;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use svg_style::SVG_STYLES;
 
+mod at_rule;
 mod svg_style;
 
 /// convenient method to create inline style for css usage.
@@ -19,8 +21,8 @@ mod svg_style;
 macro_rules! style {
     ($($tokens:tt)+) => {
         {
-            let json = $crate::json::object!{$($tokens)*};
-            $crate::process_css_properties(0, None, None, &json, false)
+            let json = $crate::__object!{$($tokens)*};
+            $crate::process_css_properties(0, None, None, &json, &$crate::Config::compact())
         }
     };
 }
@@ -28,9 +30,9 @@ macro_rules! style {
 /// return the style name matching it's ident name version
 pub(crate) fn from_ident(ident: &str) -> Option<&'static str> {
     if let Some(html_style) = HTML_STYLES.get(ident) {
-        return Some(*html_style);
+        Some(*html_style)
     } else {
-        SVG_STYLES.get(ident).map(|s| *s)
+        SVG_STYLES.get(ident).copied()
     }
 }
 
@@ -44,7 +46,95 @@ static ALL_STYLES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
 });
 
 pub(crate) fn match_name(style_name: &str) -> Option<&'static str> {
-    ALL_STYLES.get(style_name).map(|s| *s)
+    ALL_STYLES.get(style_name).copied()
+}
+
+/// return the property name from either its ident or its name
+fn property_name(ident: &str) -> Option<&'static str> {
+    from_ident(ident).or_else(|| match_name(ident))
+}
+
+/// return the name of the at-rule of the selector, ie: `@property` in `@property --x`
+fn at_rule_name(selector: &str) -> Option<&str> {
+    selector
+        .split_whitespace()
+        .next()
+        .filter(|name| name.starts_with('@'))
+}
+
+fn at_rule_of(selector: &str) -> Option<&'static AtRule> {
+    let name = at_rule_name(selector)?;
+    AT_RULE_DESCRIPTORS
+        .iter()
+        .find(|at_rule| at_rule.name == name)
+}
+
+fn descriptor_name(at_rule: &AtRule, ident: &str) -> Option<&'static str> {
+    at_rule
+        .descriptors
+        .iter()
+        .find(|(descriptor_ident, name)| *descriptor_ident == ident || *name == ident)
+        .map(|(_, name)| *name)
+}
+
+/// return the name of the property or the descriptor which can be used in the rule of the selector,
+/// ie: `syntax` can only be used in `@property` and `color` can not be used in `@font-face`.
+/// The style rules and the at-rules without descriptors such as `@media` accepts the properties
+pub(crate) fn resolve_name<'a>(ident: &'a str, selector: Option<&str>) -> Option<&'a str> {
+    // the custom properties, ie: `--accent`
+    if ident.starts_with("--") {
+        return Some(ident);
+    }
+    let Some(selector) = selector else {
+        return property_name(ident);
+    };
+    if at_rule_name(selector).is_some_and(|name| FEATURE_VALUE_BLOCKS.contains(&name)) {
+        return Some(ident);
+    }
+    match at_rule_of(selector) {
+        Some(at_rule) => descriptor_name(at_rule, ident)
+            .or_else(|| at_rule.properties.then(|| property_name(ident)).flatten()),
+        None => property_name(ident),
+    }
+}
+
+/// return the name of the property or the descriptor of any at-rule
+pub(crate) fn any_name(ident: &str) -> Option<&'static str> {
+    property_name(ident).or_else(|| {
+        AT_RULE_DESCRIPTORS
+            .iter()
+            .find_map(|at_rule| descriptor_name(at_rule, ident))
+    })
+}
+
+/// the at-rules where the descriptor can be used
+pub(crate) fn at_rules_of_descriptor(name: &str) -> Vec<&'static str> {
+    AT_RULE_DESCRIPTORS
+        .iter()
+        .filter(|at_rule| descriptor_name(at_rule, name).is_some())
+        .map(|at_rule| at_rule.name)
+        .collect()
+}
+
+/// the at-rule of the selector when its block only accepts its descriptors
+pub(crate) fn descriptors_only(selector: &str) -> Option<&'static str> {
+    at_rule_of(selector)
+        .filter(|at_rule| !at_rule.properties)
+        .map(|at_rule| at_rule.name)
+}
+
+/// the vendor prefixes of the at-rules, ie: `@-webkit-keyframes`
+const VENDOR_PREFIXES: &[&str] = &["-webkit-", "-moz-", "-ms-", "-o-"];
+
+/// whether the selector is a known at-rule, with or without a vendor prefix
+pub(crate) fn is_known_at_rule(selector: &str) -> bool {
+    at_rule_name(selector).is_some_and(|name| {
+        let unprefixed = VENDOR_PREFIXES
+            .iter()
+            .find_map(|prefix| name[1..].strip_prefix(prefix))
+            .map(|name| format!("@{}", name));
+        AT_RULES.contains(unprefixed.as_deref().unwrap_or(name))
+    })
 }
 
 /// A list of ident style in snake_case style
@@ -52,7 +142,6 @@ pub(crate) fn match_name(style_name: &str) -> Option<&'static str> {
 /// [Reference](https://developer.mozilla.org/en-US/docs/Web/CSS/Reference)
 static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "accent_color" => "accent-color",
-    "additive_symbols" => "additive-symbols",
     "align_content" => "align-content",
     "align_items" => "align-items",
     "align_self" => "align-self",
@@ -67,9 +156,7 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "animation_name" => "animation-name",
     "animation_play_state" => "animation-play-state",
     "animation_timing_function" => "animation-timing-function",
-    "@annotation" => "@annotation",
     "appearance" => "appearance",
-    "ascent_override" => "ascent-override",
     "aspect_ratio" => "aspect-ratio",
     "backdrop_filter" => "backdrop-filter",
     "backface_visibility" => "backface-visibility",
@@ -85,7 +172,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "background_position_y" => "background-position-y",
     "background_repeat" => "background-repeat",
     "background_size" => "background-size",
-    "bleed" => "bleed",
     "block_overflow" => "block-overflow",
     "block_size" => "block-size",
     "border" => "border",
@@ -150,7 +236,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "border_top_width" => "border-top-width",
     "border_width" => "border-width",
     "bottom" => "bottom",
-    "@bottom_center" => "@bottom-center",
     "box_decoration_break" => "box-decoration-break",
     "box_shadow" => "box-shadow",
     "box_sizing" => "box-sizing",
@@ -159,8 +244,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "break_inside" => "break-inside",
     "caption_side" => "caption-side",
     "caret_color" => "caret-color",
-    "@character_variant" => "@character-variant",
-    "@charset" => "@charset",
     "clear" => "clear",
     "clip" => "clip",
     "clip_path" => "clip-path",
@@ -178,18 +261,18 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "column_width" => "column-width",
     "columns" => "columns",
     "contain" => "contain",
+    "container" => "container",
+    "container_name" => "container-name",
+    "container_type" => "container-type",
     "content" => "content",
     "content_visibility" => "content-visibility",
-    "<counter>" => "<counter>",
     "counter_increment" => "counter-increment",
     "counter_reset" => "counter-reset",
     "counter_set" => "counter-set",
-    "@counter_style" => "@counter-style",
     "cursor" => "cursor",
     "direction" => "direction",
     "display" => "display",
     "empty_cells" => "empty-cells",
-    "fallback" => "fallback",
     "filter" => "filter",
     "flex" => "flex",
     "flex_basis" => "flex-basis",
@@ -200,11 +283,8 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "flex_wrap" => "flex-wrap",
     "float" => "float",
     "font" => "font",
-    "font_display" => "font-display",
-    "@font_face" => "@font-face",
     "font_family" => "font-family",
     "font_feature_settings" => "font-feature-settings",
-    "@font_feature_values" => "@font-feature-values",
     "font_kerning" => "font-kerning",
     "font_language_override" => "font-language-override",
     "font_optical_sizing" => "font-optical-sizing",
@@ -245,13 +325,8 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "image_orientation" => "image-orientation",
     "image_rendering" => "image-rendering",
     "image_resolution" => "image-resolution",
-    "@import" => "@import",
-    "inherit" => "inherit",
-    "inherits" => "inherits",
-    "initial" => "initial",
     "initial_letter" => "initial-letter",
     "initial_letter_align" => "initial-letter-align",
-    "initial_value" => "initial-value",
     "inline_size" => "inline-size",
     "inset" => "inset",
     "inset_block" => "inset-block",
@@ -265,13 +340,10 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "justify_items" => "justify-items",
     "justify_self" => "justify-self",
     "justify_tracks" => "justify-tracks",
-    "@keyframes" => "@keyframes",
     "left" => "left",
-    "@left_bottom" => "@left-bottom",
     "letter_spacing" => "letter-spacing",
     "line_break" => "line-break",
     "line_clamp" => "line-clamp",
-    "line_gap_override" => "line-gap-override",
     "line_height" => "line-height",
     "line_height_step" => "line-height-step",
     "list_style" => "list-style",
@@ -290,7 +362,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "margin_right" => "margin-right",
     "margin_top" => "margin-top",
     "margin_trim" => "margin-trim",
-    "marks" => "marks",
     "mask" => "mask",
     "mask_border" => "mask-border",
     "mask_border_mode" => "mask-border-mode",
@@ -315,16 +386,11 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "max_inline_size" => "max-inline-size",
     "max_lines" => "max-lines",
     "max_width" => "max-width",
-    "max_zoom" => "max-zoom",
-    "@media" => "@media",
     "min_block_size" => "min-block-size",
     "min_height" => "min-height",
     "min_inline_size" => "min-inline-size",
     "min_width" => "min-width",
-    "min_zoom" => "min-zoom",
     "mix_blend_mode" => "mix-blend-mode",
-    "@namespace" => "@namespace",
-    "negative" => "negative",
     "object_fit" => "object-fit",
     "object_position" => "object-position",
     "offset" => "offset",
@@ -335,8 +401,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "offset_rotate" => "offset-rotate",
     "opacity" => "opacity",
     "order" => "order",
-    "orientation" => "orientation",
-    "@ornaments" => "@ornaments",
     "orphans" => "orphans",
     "outline" => "outline",
     "outline_color" => "outline-color",
@@ -356,7 +420,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "overscroll_behavior_inline" => "overscroll-behavior-inline",
     "overscroll_behavior_x" => "overscroll-behavior-x",
     "overscroll_behavior_y" => "overscroll-behavior-y",
-    "pad" => "pad",
     "padding" => "padding",
     "padding_block" => "padding-block",
     "padding_block_end" => "padding-block-end",
@@ -368,7 +431,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "padding_left" => "padding-left",
     "padding_right" => "padding-right",
     "padding_top" => "padding-top",
-    "@page" => "@page",
     "page_break_after" => "page-break-after",
     "page_break_before" => "page-break-before",
     "page_break_inside" => "page-break-inside",
@@ -380,14 +442,9 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "place_self" => "place-self",
     "pointer_events" => "pointer-events",
     "position" => "position",
-    "prefix" => "prefix",
-    "@property" => "@property",
     "quotes" => "quotes",
-    "range" => "range",
     "resize" => "resize",
-    "revert" => "revert",
     "right" => "right",
-    "@right_bottom" => "@right-bottom",
     "rotate" => "rotate",
     "row_gap" => "row-gap",
     "ruby_align" => "ruby-align",
@@ -426,18 +483,7 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "shape_image_threshold" => "shape-image-threshold",
     "shape_margin" => "shape-margin",
     "shape_outside" => "shape-outside",
-    "size" => "size",
-    "size_adjust" => "size-adjust",
     "speak_as" => "speak-as",
-    "src" => "src",
-    "@styleset" => "@styleset",
-    "@stylistic" => "@stylistic",
-    "suffix" => "suffix",
-    "@supports" => "@supports",
-    "@swash" => "@swash",
-    "symbols" => "symbols",
-    "syntax" => "syntax",
-    "system" => "system",
     "tab_size" => "tab-size",
     "table_layout" => "table-layout",
     "text_align" => "text-align",
@@ -465,7 +511,6 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "text_underline_offset" => "text-underline-offset",
     "text_underline_position" => "text-underline-position",
     "top" => "top",
-    "@top_center" => "@top-center",
     "touch_action" => "touch-action",
     "transform" => "transform",
     "transform_box" => "transform-box",
@@ -478,13 +523,8 @@ static HTML_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
     "transition_timing_function" => "transition-timing-function",
     "translate" => "translate",
     "unicode_bidi" => "unicode-bidi",
-    "unicode_range" => "unicode-range",
-    "unset" => "unset",
     "user_select" => "user-select",
-    "user_zoom" => "user-zoom",
     "vertical_align" => "vertical-align",
-    "@viewport" => "@viewport",
-    "viewport_fit" => "viewport-fit",
     "visibility" => "visibility",
     "white_space" => "white-space",
     "widows" => "widows",
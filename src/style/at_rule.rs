@@ -0,0 +1,165 @@
+use phf::phf_set;
+
+/// The at-rules, the vendor prefixed at-rules such as `@-webkit-keyframes` are known by their unprefixed name
+/// [Reference](https://developer.mozilla.org/en-US/docs/Web/CSS/At-rule)
+pub(super) static AT_RULES: phf::Set<&'static str> = phf_set! {
+    "@annotation",
+    "@character-variant",
+    "@charset",
+    "@container",
+    "@counter-style",
+    "@font-face",
+    "@font-feature-values",
+    "@font-palette-values",
+    "@import",
+    "@keyframes",
+    "@layer",
+    "@media",
+    "@namespace",
+    "@ornaments",
+    "@page",
+    "@position-try",
+    "@property",
+    "@scope",
+    "@starting-style",
+    "@styleset",
+    "@stylistic",
+    "@supports",
+    "@swash",
+    "@view-transition",
+    "@viewport",
+    // the page-margin boxes of `@page`
+    "@top-left-corner",
+    "@top-left",
+    "@top-center",
+    "@top-right",
+    "@top-right-corner",
+    "@bottom-left-corner",
+    "@bottom-left",
+    "@bottom-center",
+    "@bottom-right",
+    "@bottom-right-corner",
+    "@left-top",
+    "@left-middle",
+    "@left-bottom",
+    "@right-top",
+    "@right-middle",
+    "@right-bottom",
+};
+
+/// The declarations which can be used in the block of an at-rule
+pub(super) struct AtRule {
+    /// the name of the at-rule, ie: `@font-face`
+    pub name: &'static str,
+    /// the descriptors of the at-rule in snake_case together with their name
+    pub descriptors: &'static [(&'static str, &'static str)],
+    /// the properties can also be used in the block, ie: `margin` in `@page`
+    pub properties: bool,
+}
+
+/// The at-rules which has descriptors
+/// [Reference](https://developer.mozilla.org/en-US/docs/Web/CSS/At-rule)
+pub(super) static AT_RULE_DESCRIPTORS: &[AtRule] = &[
+    AtRule {
+        name: "@counter-style",
+        descriptors: &[
+            ("additive_symbols", "additive-symbols"),
+            ("fallback", "fallback"),
+            ("negative", "negative"),
+            ("pad", "pad"),
+            ("prefix", "prefix"),
+            ("range", "range"),
+            ("speak_as", "speak-as"),
+            ("suffix", "suffix"),
+            ("symbols", "symbols"),
+            ("system", "system"),
+        ],
+        properties: false,
+    },
+    AtRule {
+        name: "@font-face",
+        descriptors: &[
+            ("ascent_override", "ascent-override"),
+            ("descent_override", "descent-override"),
+            ("font_display", "font-display"),
+            ("font_family", "font-family"),
+            ("font_feature_settings", "font-feature-settings"),
+            ("font_stretch", "font-stretch"),
+            ("font_style", "font-style"),
+            ("font_variation_settings", "font-variation-settings"),
+            ("font_weight", "font-weight"),
+            ("line_gap_override", "line-gap-override"),
+            ("size_adjust", "size-adjust"),
+            ("src", "src"),
+            ("unicode_range", "unicode-range"),
+        ],
+        properties: false,
+    },
+    AtRule {
+        name: "@font-feature-values",
+        descriptors: &[("font_display", "font-display")],
+        properties: false,
+    },
+    AtRule {
+        name: "@font-palette-values",
+        descriptors: &[
+            ("base_palette", "base-palette"),
+            ("font_family", "font-family"),
+            ("override_colors", "override-colors"),
+        ],
+        properties: false,
+    },
+    AtRule {
+        name: "@page",
+        descriptors: &[
+            ("bleed", "bleed"),
+            ("marks", "marks"),
+            ("page_orientation", "page-orientation"),
+            ("size", "size"),
+        ],
+        properties: true,
+    },
+    AtRule {
+        name: "@property",
+        descriptors: &[
+            ("inherits", "inherits"),
+            ("initial_value", "initial-value"),
+            ("syntax", "syntax"),
+        ],
+        properties: false,
+    },
+    AtRule {
+        name: "@view-transition",
+        descriptors: &[("navigation", "navigation"), ("types", "types")],
+        properties: false,
+    },
+    AtRule {
+        name: "@viewport",
+        descriptors: &[
+            ("height", "height"),
+            ("max_height", "max-height"),
+            ("max_width", "max-width"),
+            ("max_zoom", "max-zoom"),
+            ("min_height", "min-height"),
+            ("min_width", "min-width"),
+            ("min_zoom", "min-zoom"),
+            ("orientation", "orientation"),
+            ("user_zoom", "user-zoom"),
+            ("viewport_fit", "viewport-fit"),
+            ("width", "width"),
+            ("zoom", "zoom"),
+        ],
+        properties: false,
+    },
+];
+
+/// The blocks of `@font-feature-values` where the names are defined by the author,
+/// ie: `@styleset { nice-style: 12; }`
+pub(super) static FEATURE_VALUE_BLOCKS: &[&str] = &[
+    "@annotation",
+    "@character-variant",
+    "@ornaments",
+    "@styleset",
+    "@stylistic",
+    "@swash",
+];
@@ -0,0 +1,183 @@
+//! a builder for the `@supports` at-rule, the feature queries on the declarations and the selectors
+//! the browser supports
+//! ```rust
+//! use jss::prelude::*;
+//! use jss::supports::{declaration, selector};
+//!
+//! let blurred = declaration("backdrop_filter", blur(px(4)));
+//! let no_has = !selector(".menu:has(.open)");
+//!
+//! let css = jss::jss_ns!("nav",
+//!     ".menu": {
+//!         background_color: "white",
+//!         [&blurred.selector()]: {
+//!             background_color: "transparent",
+//!             backdrop_filter: blur(px(4)),
+//!         },
+//!     },
+//!     [&no_has.selector()]: {
+//!         ".item": { display: "block" },
+//!     },
+//! );
+//! assert_eq!(
+//!     ".nav__menu{background-color:white;@supports (backdrop-filter: blur(4px)){background-color:transparent;backdrop-filter:blur(4px);}}\
+//!      @supports not selector(.nav__menu:has(.nav__open)){.nav__item{display:block;}}",
+//!     css
+//! );
+//! ```
+//!
+//! With [`Config::supports_fallback`](crate::Config::supports_fallback),
+//! the declarations of the modern properties are moved into a `@supports` block written after
+//! their rule and the rules with a `:has()` selector are wrapped in a `@supports selector()` block.
+//! A value map of `fallback` and `supports` writes the fallback value in the rule
+//! and the other value in the `@supports` block.
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! let config = Config {
+//!     supports_fallback: true,
+//!     ..Config::compact()
+//! };
+//! let css = jss::process_css(None, &jss::json::object! {
+//!     ".panel": {
+//!         backdrop_filter: blur(px(4)),
+//!         display: { fallback: "flex", supports: "grid" },
+//!     },
+//! }, &config);
+//! assert_eq!(
+//!     ".panel{display:flex;}\
+//!      @supports (backdrop-filter: blur(4px)){.panel{backdrop-filter:blur(4px);}}\
+//!      @supports (display: grid){.panel{display:grid;}}",
+//!     css
+//! );
+//! ```
+
+use crate::media::MediaCondition;
+use crate::style;
+use crate::value::{impl_str_eq, split_top_level};
+use std::fmt;
+use std::ops::Not;
+
+/// the key of the value which is written for the browsers without the support,
+/// ie: `{ fallback: "flex", supports: "grid" }`
+pub(crate) const FALLBACK: &str = "fallback";
+
+/// the key of the value which is written in the `@supports` block
+pub(crate) const SUPPORTS: &str = "supports";
+
+/// The properties which are not supported by every browser in use,
+/// these are written in a `@supports` block when `supports_fallback` is enabled
+pub(crate) static MODERN_PROPERTIES: &[&str] = &[
+    "backdrop-filter",
+    "container",
+    "container-name",
+    "container-type",
+    "content-visibility",
+];
+
+/// A feature query on a declaration or a selector,
+/// or a combination of them with `and`, `or` and `not` (`!`)
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/@supports)
+/// ```rust
+/// use jss::supports::{declaration, selector};
+///
+/// let query = declaration("display", "grid")
+///     .and(!declaration("display", "inline-grid"))
+///     .or(selector(":has(> img)"));
+/// assert_eq!(
+///     "@supports ((display: grid) and (not (display: inline-grid))) or selector(:has(> img))",
+///     query.selector()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Supports(MediaCondition);
+
+/// the browser supports the property with the value,
+/// the property can be written in snake_case, ie: `declaration("backdrop_filter", "blur(4px)")`
+pub fn declaration(property: &str, value: impl fmt::Display) -> Supports {
+    let name = style::any_name(property).unwrap_or(property);
+    Supports(MediaCondition::Feature(format!("({}: {})", name, value)))
+}
+
+/// the browser supports the selector, ie: `selector(":has(> img)")`
+pub fn selector(selector: &str) -> Supports {
+    Supports(MediaCondition::Feature(format!("selector({})", selector)))
+}
+
+impl Supports {
+    /// both this and the other query are supported
+    pub fn and(self, other: Supports) -> Self {
+        Supports(self.0.and(other.0))
+    }
+
+    /// either this or the other query is supported
+    pub fn or(self, other: Supports) -> Self {
+        Supports(self.0.or(other.0))
+    }
+
+    /// the at-rule selector, `@supports <condition>`
+    pub fn selector(&self) -> String {
+        format!("@supports {}", self)
+    }
+}
+
+impl Not for Supports {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Supports(!self.0)
+    }
+}
+
+impl fmt::Display for Supports {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl_str_eq!(Supports);
+
+/// whether the property is in the [`MODERN_PROPERTIES`]
+pub(crate) fn is_modern(name: &str) -> bool {
+    MODERN_PROPERTIES.contains(&name)
+}
+
+/// a value map of the fallback value and the value which needs the support
+pub(crate) fn is_fallback_map(value: &json::JsonValue) -> bool {
+    value.is_object()
+        && value.has_key(SUPPORTS)
+        && value
+            .entries()
+            .all(|(key, value)| (key == FALLBACK || key == SUPPORTS) && !value.is_object())
+}
+
+/// the query on the selectors of the selector list which uses `:has()`,
+/// the whole rule is dropped by the browsers which doesn't support it
+pub(crate) fn has_selector(selector_list: &str) -> Option<Supports> {
+    split_top_level(selector_list, ',')
+        .into_iter()
+        .filter(|part| part.contains(":has("))
+        .map(|part| selector(part.trim()))
+        .reduce(Supports::and)
+}
+
+const SELECTOR_FN: &str = "selector(";
+
+/// namespace the classes inside the `selector()` functions of a `@supports` selector
+pub(crate) fn selector_namespaced(namespace: &str, at_rule: &str) -> Option<String> {
+    if !at_rule.trim_start().starts_with("@supports") || !at_rule.contains(SELECTOR_FN) {
+        return None;
+    }
+    let mut namespaced = String::new();
+    let mut rest = at_rule;
+    while let Some(start) = rest.find(SELECTOR_FN) {
+        let (before, after) = rest.split_at(start + SELECTOR_FN.len());
+        namespaced.push_str(before);
+        // the closing parenthesis of `selector(`, the selector may have its own parenthesis
+        let end = split_top_level(after, ')')[0].len();
+        namespaced.push_str(&crate::selector_namespaced(namespace, &after[..end]));
+        rest = &after[end..];
+    }
+    namespaced.push_str(rest);
+    Some(namespaced)
+}
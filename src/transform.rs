@@ -0,0 +1,376 @@
+//! the css transform functions, composed into a [`Transform`] list
+//! ```rust
+//! use jss::prelude::*;
+//!
+//! let transform = translate(px(10), px(20)).rotate(deg(45)).scale(1.2);
+//! assert_eq!("translate(10px, 20px) rotate(45deg) scale(1.2)", transform);
+//!
+//! assert_eq!(
+//!     Some("matrix(1, 0, 0, 1, 30, 20)".to_string()),
+//!     translate_x(px(10)).translate(px(20), px(20)).to_matrix().map(|m| m.to_string())
+//! );
+//! ```
+
+use crate::units::{self, Angle, AngleUnit, Length, LengthUnit};
+use crate::value::impl_css_value;
+use std::fmt;
+
+/// A single css transform function
+/// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransformFunction {
+    /// `translate(x, y)`
+    Translate(Length, Length),
+    /// `translateX(x)`
+    TranslateX(Length),
+    /// `translateY(y)`
+    TranslateY(Length),
+    /// `translateZ(z)`
+    TranslateZ(Length),
+    /// `translate3d(x, y, z)`
+    Translate3d(Length, Length, Length),
+    /// `rotate(angle)`
+    Rotate(Angle),
+    /// `rotateX(angle)`
+    RotateX(Angle),
+    /// `rotateY(angle)`
+    RotateY(Angle),
+    /// `rotateZ(angle)`
+    RotateZ(Angle),
+    /// `rotate3d(x, y, z, angle)`, the rotation around the vector x, y, z
+    Rotate3d(f64, f64, f64, Angle),
+    /// `scale(sx, sy)`, written as `scale(s)` when both are the same
+    Scale(f64, f64),
+    /// `scaleX(s)`
+    ScaleX(f64),
+    /// `scaleY(s)`
+    ScaleY(f64),
+    /// `scaleZ(s)`
+    ScaleZ(f64),
+    /// `scale3d(sx, sy, sz)`
+    Scale3d(f64, f64, f64),
+    /// `skew(ax, ay)`
+    Skew(Angle, Angle),
+    /// `skewX(angle)`
+    SkewX(Angle),
+    /// `skewY(angle)`
+    SkewY(Angle),
+    /// `matrix(a, b, c, d, e, f)`
+    Matrix([f64; 6]),
+    /// `matrix3d(...)` with the 16 values in column-major order
+    Matrix3d([f64; 16]),
+    /// `perspective(d)`
+    Perspective(Length),
+}
+
+/// A space separated list of transform functions, applied from left to right.
+/// An empty list is written as `none`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transform(pub Vec<TransformFunction>);
+
+const IDENTITY: [f64; 16] = [
+    1.0, 0.0, 0.0, 0.0, //
+    0.0, 1.0, 0.0, 0.0, //
+    0.0, 0.0, 1.0, 0.0, //
+    0.0, 0.0, 0.0, 1.0,
+];
+
+impl TransformFunction {
+    /// the 4x4 matrix of this function in column-major order,
+    /// `None` if a length is relative such as `%` or `em`
+    fn matrix(&self) -> Option<[f64; 16]> {
+        let px = |length: &Length| length.to(LengthUnit::Px).map(|l| l.value());
+        let rad = |angle: &Angle| angle.to(AngleUnit::Rad).map(|a| a.value());
+        let mut m = IDENTITY;
+        match self {
+            TransformFunction::Translate(x, y) => {
+                m[12] = px(x)?;
+                m[13] = px(y)?;
+            }
+            TransformFunction::TranslateX(x) => m[12] = px(x)?,
+            TransformFunction::TranslateY(y) => m[13] = px(y)?,
+            TransformFunction::TranslateZ(z) => m[14] = px(z)?,
+            TransformFunction::Translate3d(x, y, z) => {
+                m[12] = px(x)?;
+                m[13] = px(y)?;
+                m[14] = px(z)?;
+            }
+            TransformFunction::Rotate(angle) | TransformFunction::RotateZ(angle) => {
+                let (sin, cos) = rad(angle)?.sin_cos();
+                m[0] = cos;
+                m[1] = sin;
+                m[4] = -sin;
+                m[5] = cos;
+            }
+            TransformFunction::RotateX(angle) => {
+                let (sin, cos) = rad(angle)?.sin_cos();
+                m[5] = cos;
+                m[6] = sin;
+                m[9] = -sin;
+                m[10] = cos;
+            }
+            TransformFunction::RotateY(angle) => {
+                let (sin, cos) = rad(angle)?.sin_cos();
+                m[0] = cos;
+                m[2] = -sin;
+                m[8] = sin;
+                m[10] = cos;
+            }
+            TransformFunction::Rotate3d(x, y, z, angle) => {
+                let length = (x * x + y * y + z * z).sqrt();
+                if length == 0.0 {
+                    return Some(m);
+                }
+                let (x, y, z) = (x / length, y / length, z / length);
+                let half = rad(angle)? / 2.0;
+                let sc = half.sin() * half.cos();
+                let sq = half.sin().powi(2);
+                m[0] = 1.0 - 2.0 * (y * y + z * z) * sq;
+                m[1] = 2.0 * (x * y * sq + z * sc);
+                m[2] = 2.0 * (x * z * sq - y * sc);
+                m[4] = 2.0 * (x * y * sq - z * sc);
+                m[5] = 1.0 - 2.0 * (x * x + z * z) * sq;
+                m[6] = 2.0 * (y * z * sq + x * sc);
+                m[8] = 2.0 * (x * z * sq + y * sc);
+                m[9] = 2.0 * (y * z * sq - x * sc);
+                m[10] = 1.0 - 2.0 * (x * x + y * y) * sq;
+            }
+            TransformFunction::Scale(sx, sy) => {
+                m[0] = *sx;
+                m[5] = *sy;
+            }
+            TransformFunction::ScaleX(s) => m[0] = *s,
+            TransformFunction::ScaleY(s) => m[5] = *s,
+            TransformFunction::ScaleZ(s) => m[10] = *s,
+            TransformFunction::Scale3d(sx, sy, sz) => {
+                m[0] = *sx;
+                m[5] = *sy;
+                m[10] = *sz;
+            }
+            TransformFunction::Skew(ax, ay) => {
+                m[4] = rad(ax)?.tan();
+                m[1] = rad(ay)?.tan();
+            }
+            TransformFunction::SkewX(angle) => m[4] = rad(angle)?.tan(),
+            TransformFunction::SkewY(angle) => m[1] = rad(angle)?.tan(),
+            TransformFunction::Matrix([a, b, c, d, e, f]) => {
+                m[0] = *a;
+                m[1] = *b;
+                m[4] = *c;
+                m[5] = *d;
+                m[12] = *e;
+                m[13] = *f;
+            }
+            TransformFunction::Matrix3d(values) => m = *values,
+            TransformFunction::Perspective(d) => {
+                let d = px(d)?;
+                if d != 0.0 {
+                    m[11] = -1.0 / d;
+                }
+            }
+        }
+        Some(m)
+    }
+
+    /// the `matrix()` if the 4x4 matrix is a 2D transform, otherwise the `matrix3d()`
+    fn from_matrix(m: [f64; 16]) -> Self {
+        let is_2d = [2, 3, 6, 7, 8, 9, 11, 14]
+            .iter()
+            .all(|i| m[*i].abs() < 1e-12)
+            && (m[10] - 1.0).abs() < 1e-12
+            && (m[15] - 1.0).abs() < 1e-12;
+        if is_2d {
+            TransformFunction::Matrix([m[0], m[1], m[4], m[5], m[12], m[13]])
+        } else {
+            TransformFunction::Matrix3d(m)
+        }
+    }
+}
+
+/// multiply the column-major matrices, the result applies `b` first then `a`
+fn multiply(a: &[f64; 16], b: &[f64; 16]) -> [f64; 16] {
+    let mut result = [0.0; 16];
+    for col in 0..4 {
+        for row in 0..4 {
+            result[col * 4 + row] = (0..4).map(|k| a[k * 4 + row] * b[col * 4 + k]).sum();
+        }
+    }
+    result
+}
+
+impl Transform {
+    /// an empty list of transform functions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// append the transform function to the list
+    pub fn push(mut self, function: TransformFunction) -> Self {
+        self.0.push(function);
+        self
+    }
+
+    /// reduce the list into a single equivalent `matrix()` or `matrix3d()`,
+    /// `None` if a length is relative such as `%` or `em` since it is only known by the browser
+    /// ```rust
+    /// use jss::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     "matrix(0, 2, -2, 0, 0, 0)",
+    ///     rotate(deg(90)).scale(2.0).to_matrix().unwrap()
+    /// );
+    /// assert_eq!(None, translate_x(percent(50)).to_matrix());
+    /// ```
+    pub fn to_matrix(&self) -> Option<TransformFunction> {
+        let mut m = IDENTITY;
+        for function in self.0.iter() {
+            m = multiply(&m, &function.matrix()?);
+        }
+        Some(TransformFunction::from_matrix(m))
+    }
+}
+
+/// write the function with the numbers as its arguments, ie: `scale(1.5, 2)`
+fn write_function(f: &mut fmt::Formatter, name: &str, values: &[f64]) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    units::write_numbers(f, values)?;
+    write!(f, ")")
+}
+
+impl fmt::Display for TransformFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransformFunction::Translate(x, y) => write!(f, "translate({}, {})", x, y),
+            TransformFunction::TranslateX(x) => write!(f, "translateX({})", x),
+            TransformFunction::TranslateY(y) => write!(f, "translateY({})", y),
+            TransformFunction::TranslateZ(z) => write!(f, "translateZ({})", z),
+            TransformFunction::Translate3d(x, y, z) => {
+                write!(f, "translate3d({}, {}, {})", x, y, z)
+            }
+            TransformFunction::Rotate(angle) => write!(f, "rotate({})", angle),
+            TransformFunction::RotateX(angle) => write!(f, "rotateX({})", angle),
+            TransformFunction::RotateY(angle) => write!(f, "rotateY({})", angle),
+            TransformFunction::RotateZ(angle) => write!(f, "rotateZ({})", angle),
+            TransformFunction::Rotate3d(x, y, z, angle) => {
+                write!(f, "rotate3d(")?;
+                units::write_numbers(f, &[*x, *y, *z])?;
+                write!(f, ", {})", angle)
+            }
+            TransformFunction::Scale(sx, sy) if sx == sy => write_function(f, "scale", &[*sx]),
+            TransformFunction::Scale(sx, sy) => write_function(f, "scale", &[*sx, *sy]),
+            TransformFunction::ScaleX(s) => write_function(f, "scaleX", &[*s]),
+            TransformFunction::ScaleY(s) => write_function(f, "scaleY", &[*s]),
+            TransformFunction::ScaleZ(s) => write_function(f, "scaleZ", &[*s]),
+            TransformFunction::Scale3d(sx, sy, sz) => {
+                write_function(f, "scale3d", &[*sx, *sy, *sz])
+            }
+            TransformFunction::Skew(ax, ay) => write!(f, "skew({}, {})", ax, ay),
+            TransformFunction::SkewX(angle) => write!(f, "skewX({})", angle),
+            TransformFunction::SkewY(angle) => write!(f, "skewY({})", angle),
+            TransformFunction::Matrix(values) => write_function(f, "matrix", values),
+            TransformFunction::Matrix3d(values) => write_function(f, "matrix3d", values),
+            TransformFunction::Perspective(d) => write!(f, "perspective({})", d),
+        }
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+        for (i, function) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", function)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<TransformFunction> for Transform {
+    fn from(function: TransformFunction) -> Self {
+        Self(vec![function])
+    }
+}
+
+impl_css_value!(Transform, TransformFunction);
+
+/// declare the transform functions as a function which starts a [`Transform`]
+/// and as a method of [`Transform`] which appends to it
+macro_rules! declare_transforms {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($($arg:ident: $ty:ty),*) => $variant:ident($($value:expr),*);
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name($($arg: $ty),*) -> Transform {
+                Transform::new().$name($($arg),*)
+            }
+        )*
+
+        impl Transform {
+            $(
+                $(#[$attr])*
+                pub fn $name(self, $($arg: $ty),*) -> Self {
+                    self.push(TransformFunction::$variant($($value),*))
+                }
+            )*
+        }
+    };
+}
+
+declare_transforms! {
+    /// move by x horizontally and y vertically
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/translate)
+    translate(x: Length, y: Length) => Translate(x, y);
+    /// move horizontally
+    translate_x(x: Length) => TranslateX(x);
+    /// move vertically
+    translate_y(y: Length) => TranslateY(y);
+    /// move along the z axis
+    translate_z(z: Length) => TranslateZ(z);
+    /// move in the 3D space
+    translate3d(x: Length, y: Length, z: Length) => Translate3d(x, y, z);
+    /// rotate around the origin
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/rotate)
+    rotate(angle: Angle) => Rotate(angle);
+    /// rotate around the horizontal axis
+    rotate_x(angle: Angle) => RotateX(angle);
+    /// rotate around the vertical axis
+    rotate_y(angle: Angle) => RotateY(angle);
+    /// rotate around the z axis
+    rotate_z(angle: Angle) => RotateZ(angle);
+    /// rotate around the vector x, y, z
+    rotate3d(x: f64, y: f64, z: f64, angle: Angle) => Rotate3d(x, y, z, angle);
+    /// resize by the same factor in both directions
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/scale)
+    scale(s: f64) => Scale(s, s);
+    /// resize by sx horizontally and sy vertically
+    scale_xy(sx: f64, sy: f64) => Scale(sx, sy);
+    /// resize horizontally
+    scale_x(s: f64) => ScaleX(s);
+    /// resize vertically
+    scale_y(s: f64) => ScaleY(s);
+    /// resize along the z axis
+    scale_z(s: f64) => ScaleZ(s);
+    /// resize in the 3D space
+    scale3d(sx: f64, sy: f64, sz: f64) => Scale3d(sx, sy, sz);
+    /// skew by ax horizontally and ay vertically
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/skew)
+    skew(ax: Angle, ay: Angle) => Skew(ax, ay);
+    /// skew horizontally
+    skew_x(angle: Angle) => SkewX(angle);
+    /// skew vertically
+    skew_y(angle: Angle) => SkewY(angle);
+    /// a 2D transformation matrix
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/matrix)
+    matrix(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) => Matrix([a, b, c, d, e, f]);
+    /// a 4x4 transformation matrix with the values in column-major order
+    matrix3d(values: [f64; 16]) => Matrix3d(values);
+    /// the distance in between the user and the z=0 plane
+    /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function/perspective)
+    perspective(d: Length) => Perspective(d);
+}
@@ -1,57 +1,34 @@
 //! provides function and macro for html units such as px, %, em, etc.
 
 pub use crate::fns::rgb;
-use crate::value::Value;
-
-#[inline]
-fn unit<V>(unit_name: &str, v: V) -> String
-where
-    V: Into<Value>,
-{
-    let value: Value = v.into();
-    match value {
-        Value::Vec(values) => values
-            .into_iter()
-            .map(|v| format!("{}{}", Into::<Value>::into(v), unit_name))
-            .collect::<Vec<_>>()
-            .join(" "),
-        _ => {
-            format!("{}{}", value, unit_name)
-        }
-    }
-}
+pub use calc::Calc;
+pub use number::{format_number, NumberError, DEFAULT_PRECISION};
+pub(crate) use number::{write_number, write_numbers};
+pub use quantity::{
+    Angle, AngleUnit, Flex, FlexUnit, Frequency, FrequencyUnit, IntoQuantity, Length, LengthUnit,
+    Number, Percentage, PercentageUnit, Quantity, QuantityList, Resolution, ResolutionUnit, Time,
+    TimeUnit, Unit,
+};
+
+mod calc;
+mod number;
+mod quantity;
 
 macro_rules! declare_units{
-    (  $(
-            $(#[$attr:meta])*
-            $name:ident;
-        )*
-    ) => {
-        $(
-            $(#[$attr])*
-            ///
-            /// [MDN reference](https://developer.mozilla.org/en-US/docs/Learn/CSS/Building_blocks/Values_and_units)
-            pub fn $name<V>(v: V) -> String
-                where V: Into<Value>
-            {
-                unit(stringify!($name), v)
-            }
-        )*
-    };
     (
         $(
             $(#[$attr:meta])*
-            $name:ident => $unit:tt;
+            $name:ident => $unit:ident::$variant:ident;
          )*
     ) => {
         $(
             $(#[$attr])*
             ///
             /// [MDN reference](https://developer.mozilla.org/en-US/docs/Learn/CSS/Building_blocks/Values_and_units)
-            pub fn $name<V>(v: V) -> String
-                where V: Into<Value>
+            pub fn $name<V>(v: V) -> V::Output
+                where V: IntoQuantity<$unit>
               {
-                  unit($unit, v)
+                  v.into_quantity($unit::$variant)
               }
         )*
     }
@@ -69,7 +46,7 @@ declare_units! {
     ///
     /// assert_eq!("10px", px(10));
     /// ```
-    px;
+    px => LengthUnit::Px;
     /// 1q is equivalent to 1/40th of 1cm.
     ///
     /// Example:
@@ -78,7 +55,7 @@ declare_units! {
     ///
     /// assert_eq!("10q", q(10));
     /// ```
-    q;
+    q => LengthUnit::Q;
     /// milimeters
     ///
     /// Example:
@@ -87,7 +64,7 @@ declare_units! {
     ///
     /// assert_eq!("10mm", mm(10));
     /// ```
-    mm;
+    mm => LengthUnit::Mm;
     /// centimeters
     ///
     /// Example:
@@ -96,7 +73,7 @@ declare_units! {
     ///
     /// assert_eq!("10cm", cm(10));
     /// ```
-    cm;
+    cm => LengthUnit::Cm;
     /// points (1pt = 1/72 of 1in)
     ///
     /// Example:
@@ -105,7 +82,7 @@ declare_units! {
     ///
     /// assert_eq!("10pt", pt(10));
     /// ```
-    pt;
+    pt => LengthUnit::Pt;
     /// picas (1pc = 12 pt)
     ///
     /// Example:
@@ -114,7 +91,7 @@ declare_units! {
     ///
     /// assert_eq!("10pc", pc(10));
     /// ```
-    pc;
+    pc => LengthUnit::Pc;
     /// Relative to the font-size of the element (2em means 2 times the size of the current font)
     ///
     /// Example:
@@ -123,7 +100,7 @@ declare_units! {
     ///
     /// assert_eq!("10em", em(10));
     /// ```
-    em;
+    em => LengthUnit::Em;
     /// Relative to the x-height of the current font (rarely used)
     ///
     /// Example:
@@ -132,7 +109,7 @@ declare_units! {
     ///
     /// assert_eq!("10ex", ex(10));
     /// ```
-    ex;
+    ex => LengthUnit::Ex;
     /// Relative to the width of the "0" (zero)
     ///
     /// Example:
@@ -141,7 +118,7 @@ declare_units! {
     ///
     /// assert_eq!("10ch", ch(10));
     /// ```
-    ch;
+    ch => LengthUnit::Ch;
     /// Relative to font-size of the root element
     ///
     /// Example:
@@ -150,7 +127,7 @@ declare_units! {
     ///
     /// assert_eq!("10rem", rem(10));
     /// ```
-    rem;
+    rem => LengthUnit::Rem;
     /// Relative to 1% of the width of the viewport*
     ///
     /// Example:
@@ -159,7 +136,7 @@ declare_units! {
     ///
     /// assert_eq!("10vw", vw(10));
     /// ```
-    vw;
+    vw => LengthUnit::Vw;
     /// Relative to 1% of the height of the viewport*
     ///
     /// Example:
@@ -168,7 +145,7 @@ declare_units! {
     ///
     /// assert_eq!("10vh", vh(10));
     /// ```
-    vh;
+    vh => LengthUnit::Vh;
 }
 
 declare_units! {
@@ -179,7 +156,7 @@ declare_units! {
     ///
     /// assert_eq!("10in", r#in(10))
     /// ```
-    r#in => "in";
+    r#in => LengthUnit::In;
     /// percentage
     /// Example:
     /// ```rust
@@ -187,33 +164,182 @@ declare_units! {
     ///
     /// assert_eq!("10%", percent(10))
     /// ```
-    percent => "%";
+    percent => LengthUnit::Percent;
+    /// a percentage for the values which can not be a length, ie: `font-stretch` of `@font-face`
+    /// Example:
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!("75%", percentage(75))
+    /// ```
+    percentage => PercentageUnit::Percent;
 }
 
 // angle units
 declare_units! {
     /// Represent an angle in degrees
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/angle
-    deg;
+    deg => AngleUnit::Deg;
     /// Represent an angle in radians
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/angle
-    rad;
+    rad => AngleUnit::Rad;
     /// Represents an angle in gradians
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/angle
-    grad;
+    grad => AngleUnit::Grad;
     /// Represents an angle in a number of turns. One full circle is 1turn.
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/angle
-    turn;
+    turn => AngleUnit::Turn;
 }
 
 // time units
 declare_units! {
    /// Represents a time in seconds.
    /// https://developer.mozilla.org/en-US/docs/Web/CSS/time
-   s;
+   s => TimeUnit::S;
    /// Represents a time in milliseconds.
    /// https://developer.mozilla.org/en-US/docs/Web/CSS/time
-   ms;
+   ms => TimeUnit::Ms;
+}
+
+// viewport percentage units
+declare_units! {
+    /// Relative to 1% of the viewport's smaller dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    vmin => LengthUnit::Vmin;
+    /// Relative to 1% of the viewport's larger dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    vmax => LengthUnit::Vmax;
+    /// Relative to 1% of the viewport in the inline axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    vi => LengthUnit::Vi;
+    /// Relative to 1% of the viewport in the block axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    vb => LengthUnit::Vb;
+    /// Relative to 1% of the width of the small viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svw => LengthUnit::Svw;
+    /// Relative to 1% of the height of the small viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svh => LengthUnit::Svh;
+    /// Relative to 1% of the small viewport in the inline axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svi => LengthUnit::Svi;
+    /// Relative to 1% of the small viewport in the block axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svb => LengthUnit::Svb;
+    /// Relative to 1% of the small viewport's smaller dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svmin => LengthUnit::Svmin;
+    /// Relative to 1% of the small viewport's larger dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    svmax => LengthUnit::Svmax;
+    /// Relative to 1% of the width of the large viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvw => LengthUnit::Lvw;
+    /// Relative to 1% of the height of the large viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvh => LengthUnit::Lvh;
+    /// Relative to 1% of the large viewport in the inline axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvi => LengthUnit::Lvi;
+    /// Relative to 1% of the large viewport in the block axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvb => LengthUnit::Lvb;
+    /// Relative to 1% of the large viewport's smaller dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvmin => LengthUnit::Lvmin;
+    /// Relative to 1% of the large viewport's larger dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    lvmax => LengthUnit::Lvmax;
+    /// Relative to 1% of the width of the dynamic viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvw => LengthUnit::Dvw;
+    /// Relative to 1% of the height of the dynamic viewport
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvh => LengthUnit::Dvh;
+    /// Relative to 1% of the dynamic viewport in the inline axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvi => LengthUnit::Dvi;
+    /// Relative to 1% of the dynamic viewport in the block axis
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvb => LengthUnit::Dvb;
+    /// Relative to 1% of the dynamic viewport's smaller dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvmin => LengthUnit::Dvmin;
+    /// Relative to 1% of the dynamic viewport's larger dimension
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_viewport
+    dvmax => LengthUnit::Dvmax;
+}
+
+// container query length units
+declare_units! {
+    /// Relative to 1% of the width of the query container
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqw => LengthUnit::Cqw;
+    /// Relative to 1% of the height of the query container
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqh => LengthUnit::Cqh;
+    /// Relative to 1% of the inline size of the query container
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqi => LengthUnit::Cqi;
+    /// Relative to 1% of the block size of the query container
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqb => LengthUnit::Cqb;
+    /// The smaller value of cqi or cqb
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqmin => LengthUnit::Cqmin;
+    /// The larger value of cqi or cqb
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_containment/Container_queries#container_query_length_units
+    cqmax => LengthUnit::Cqmax;
+}
+
+// font relative units
+declare_units! {
+    /// Relative to the line-height of the element
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_font
+    lh => LengthUnit::Lh;
+    /// Relative to the line-height of the root element
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_font
+    rlh => LengthUnit::Rlh;
+    /// Relative to the cap height, the nominal height of the capital letters of the font
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_font
+    cap => LengthUnit::Cap;
+    /// Relative to the advance measure of the "水" glyph of the font
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/length#relative_length_units_based_on_font
+    ic => LengthUnit::Ic;
+}
+
+// flex unit
+declare_units! {
+    /// Represents a fraction of the leftover space in the grid container
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/flex_value
+    fr => FlexUnit::Fr;
+}
+
+// resolution units
+declare_units! {
+    /// Represents the number of dots per inch
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/resolution
+    dpi => ResolutionUnit::Dpi;
+    /// Represents the number of dots per centimeter
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/resolution
+    dpcm => ResolutionUnit::Dpcm;
+    /// Represents the number of dots per px unit
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/resolution
+    dppx => ResolutionUnit::Dppx;
+    /// Alias for dppx
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/resolution
+    x => ResolutionUnit::X;
+}
+
+// frequency units
+declare_units! {
+    /// Represents a frequency in hertz
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/frequency
+    hz => FrequencyUnit::Hz;
+    /// Represents a frequency in kilohertz
+    /// https://developer.mozilla.org/en-US/docs/Web/CSS/frequency
+    khz => FrequencyUnit::Khz;
 }
 
 #[cfg(test)]
@@ -229,5 +355,22 @@ mod tests {
         assert_eq!(pc(5), "5pc");
         assert_eq!(r#in(2.5), "2.5in");
         assert_eq!(ch(1), "1ch");
+        assert_eq!(vmin(50), "50vmin");
+        assert_eq!(dvh(100), "100dvh");
+        assert_eq!(svw(100), "100svw");
+        assert_eq!(lvmax(10), "10lvmax");
+        assert_eq!(cqi(50), "50cqi");
+        assert_eq!(cqmin(5), "5cqmin");
+        assert_eq!(lh(1.5), "1.5lh");
+        assert_eq!(rlh(2), "2rlh");
+        assert_eq!(cap(1), "1cap");
+        assert_eq!(ic(1), "1ic");
+        assert_eq!(fr(1), "1fr");
+        assert_eq!(dpi(96), "96dpi");
+        assert_eq!(dpcm(38), "38dpcm");
+        assert_eq!(dppx(2), "2dppx");
+        assert_eq!(x(2), "2x");
+        assert_eq!(hz(440), "440hz");
+        assert_eq!(khz(1.5), "1.5khz");
     }
 }
@@ -0,0 +1,282 @@
+//! arithmetic on quantities which can not be computed ahead of time, rendered as `calc()`
+
+use super::number;
+use super::quantity::{Quantity, Unit};
+use crate::value::impl_css_value;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A sum of quantities, the quantities which can be converted into each other are folded
+/// into a single term while the rest are kept and rendered using `calc()`.
+///
+/// Adding or subtracting quantities results in a `Calc`, which is written as a plain quantity
+/// when every unit is folded, use [`Calc::as_quantity`] to get the folded quantity back.
+/// ```rust
+/// use jss::prelude::*;
+///
+/// assert_eq!("14px", px(10) + px(4));
+/// assert_eq!(Some(px(14)), (px(10) + px(4)).as_quantity());
+/// assert_eq!("calc(100% - 20px)", percent(100) - px(20));
+/// assert_eq!("calc(50% - 10px)", (percent(100) - px(20)) / 2.0);
+/// assert_eq!("calc(100% - 2rem - 14px)", percent(100) - rem(2) - px(10) - px(4));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calc<U> {
+    terms: Vec<Term<U>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Term<U> {
+    Quantity(Quantity<U>),
+    /// a math function multiplied by a coefficient
+    Function(f64, MathFunction<U>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MathFunction<U> {
+    Min(Vec<Calc<U>>),
+    Max(Vec<Calc<U>>),
+    Clamp(Box<[Calc<U>; 3]>),
+    /// a value only known by the browser such as `var()` and `env()`
+    Substitution(String),
+}
+
+impl<U: Unit> Calc<U> {
+    /// the smallest of the values, rendered as `min()`
+    pub fn min(values: impl IntoIterator<Item = Calc<U>>) -> Self {
+        Self::function(MathFunction::Min(values.into_iter().collect()))
+    }
+
+    /// the largest of the values, rendered as `max()`
+    pub fn max(values: impl IntoIterator<Item = Calc<U>>) -> Self {
+        Self::function(MathFunction::Max(values.into_iter().collect()))
+    }
+
+    /// the preferred value limited in between the minimum and maximum, rendered as `clamp()`
+    pub fn clamp(min: Calc<U>, preferred: Calc<U>, max: Calc<U>) -> Self {
+        Self::function(MathFunction::Clamp(Box::new([min, preferred, max])))
+    }
+
+    /// the quantity if the whole expression is folded into a single quantity
+    pub fn as_quantity(&self) -> Option<Quantity<U>> {
+        match self.terms.as_slice() {
+            [Term::Quantity(quantity)] => Some(*quantity),
+            _ => None,
+        }
+    }
+
+    /// a value only known by the browser such as `var(--gutter)`
+    pub(crate) fn substitution(value: String) -> Self {
+        Self::function(MathFunction::Substitution(value))
+    }
+
+    fn function(function: MathFunction<U>) -> Self {
+        Self {
+            terms: vec![Term::Function(1.0, function)],
+        }
+    }
+
+    /// add a quantity into the term which has a compatible unit, or as a new term.
+    /// The terms which cancel out are removed, unless it is the only term left
+    fn push(&mut self, quantity: Quantity<U>) {
+        for (i, term) in self.terms.iter_mut().enumerate() {
+            if let Term::Quantity(existing) = term {
+                if let Some(folded) = existing.fold(quantity) {
+                    *existing = folded;
+                    if existing.value() == 0.0 && self.terms.len() > 1 {
+                        self.terms.remove(i);
+                    }
+                    return;
+                }
+            }
+        }
+        self.terms.push(Term::Quantity(quantity));
+    }
+
+    fn scale(mut self, factor: f64) -> Self {
+        for term in self.terms.iter_mut() {
+            match term {
+                Term::Quantity(quantity) => *quantity = *quantity * factor,
+                Term::Function(coefficient, _) => *coefficient *= factor,
+            }
+        }
+        self
+    }
+
+    /// write the expression without the enclosing `calc()`,
+    /// which is how it is written inside the math functions
+    fn write_sum(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            let (negative, term) = match term {
+                Term::Quantity(q) if i > 0 && q.value() < 0.0 => (true, Term::Quantity(-*q)),
+                Term::Function(c, function) if i > 0 && *c < 0.0 => {
+                    (true, Term::Function(-c, function.clone()))
+                }
+                _ => (false, term.clone()),
+            };
+            if i > 0 {
+                write!(f, "{}", if negative { " - " } else { " + " })?;
+            }
+            match term {
+                Term::Quantity(q) => fmt::Display::fmt(&q, f)?,
+                Term::Function(1.0, function) => fmt::Display::fmt(&function, f)?,
+                Term::Function(c, function) => {
+                    number::write_number(f, c)?;
+                    write!(f, " * ")?;
+                    fmt::Display::fmt(&function, f)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<U: Unit> fmt::Display for MathFunction<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, args) = match self {
+            MathFunction::Min(args) => ("min", args.as_slice()),
+            MathFunction::Max(args) => ("max", args.as_slice()),
+            MathFunction::Clamp(args) => ("clamp", args.as_slice()),
+            MathFunction::Substitution(value) => return f.write_str(value),
+        };
+        write!(f, "{}(", name)?;
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            arg.write_sum(f)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<U: Unit> fmt::Display for Calc<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.terms.as_slice() {
+            [Term::Quantity(quantity)] => fmt::Display::fmt(quantity, f),
+            [Term::Function(c, function)] if *c == 1.0 => fmt::Display::fmt(function, f),
+            _ => {
+                write!(f, "calc(")?;
+                self.write_sum(f)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl<U: Unit> From<Quantity<U>> for Calc<U> {
+    fn from(quantity: Quantity<U>) -> Self {
+        Self {
+            terms: vec![Term::Quantity(quantity)],
+        }
+    }
+}
+
+impl<U: Unit, T: Into<Calc<U>>> Add<T> for Calc<U> {
+    type Output = Calc<U>;
+
+    fn add(mut self, rhs: T) -> Calc<U> {
+        for term in rhs.into().terms {
+            match term {
+                Term::Quantity(quantity) => self.push(quantity),
+                function => self.terms.push(function),
+            }
+        }
+        self
+    }
+}
+
+impl<U: Unit, T: Into<Calc<U>>> Sub<T> for Calc<U> {
+    type Output = Calc<U>;
+
+    fn sub(self, rhs: T) -> Calc<U> {
+        self + -rhs.into()
+    }
+}
+
+impl<U: Unit> Mul<f64> for Calc<U> {
+    type Output = Calc<U>;
+
+    fn mul(self, rhs: f64) -> Calc<U> {
+        self.scale(rhs)
+    }
+}
+
+impl<U: Unit> Div<f64> for Calc<U> {
+    type Output = Calc<U>;
+
+    fn div(self, rhs: f64) -> Calc<U> {
+        self.scale(1.0 / rhs)
+    }
+}
+
+impl<U: Unit> Neg for Calc<U> {
+    type Output = Calc<U>;
+
+    fn neg(self) -> Calc<U> {
+        self.scale(-1.0)
+    }
+}
+
+/// the quantities are added in the unit of the left operand when the units can be converted,
+/// otherwise the sum is rendered using `calc()`
+/// ```rust
+/// use jss::units::*;
+///
+/// assert_eq!(px(14), px(10) + px(4));
+/// assert_eq!(r#in(2), r#in(1) + px(96));
+/// assert_eq!("calc(100% + 20px)", percent(100) + px(20));
+/// ```
+impl<U: Unit> Add for Quantity<U> {
+    type Output = Calc<U>;
+
+    fn add(self, rhs: Self) -> Calc<U> {
+        Calc::from(self) + rhs
+    }
+}
+
+/// the quantities are subtracted in the unit of the left operand when the units can be converted,
+/// otherwise the difference is rendered using `calc()`
+/// ```rust
+/// use jss::units::*;
+///
+/// assert_eq!(px(6), px(10) - px(4));
+/// assert_eq!("calc(100% - 20px)", percent(100) - px(20));
+/// ```
+impl<U: Unit> Sub for Quantity<U> {
+    type Output = Calc<U>;
+
+    fn sub(self, rhs: Self) -> Calc<U> {
+        Calc::from(self) - rhs
+    }
+}
+
+impl<U: Unit> Add<Calc<U>> for Quantity<U> {
+    type Output = Calc<U>;
+
+    fn add(self, rhs: Calc<U>) -> Calc<U> {
+        Calc::from(self) + rhs
+    }
+}
+
+impl<U: Unit> Sub<Calc<U>> for Quantity<U> {
+    type Output = Calc<U>;
+
+    fn sub(self, rhs: Calc<U>) -> Calc<U> {
+        Calc::from(self) - rhs
+    }
+}
+
+impl<U: Unit> PartialEq<Quantity<U>> for Calc<U> {
+    fn eq(&self, other: &Quantity<U>) -> bool {
+        self.as_quantity().as_ref() == Some(other)
+    }
+}
+
+impl<U: Unit> PartialEq<Calc<U>> for Quantity<U> {
+    fn eq(&self, other: &Calc<U>) -> bool {
+        other == self
+    }
+}
+
+impl_css_value!(<U: Unit> Calc<U>);
@@ -0,0 +1,113 @@
+//! deterministic formatting of the numbers used in the css values
+
+use std::fmt;
+
+/// the maximum number of decimal places used when no precision is specified
+pub const DEFAULT_PRECISION: usize = 6;
+
+/// The error when a number can not be written as a css number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberError {
+    /// the number is NaN
+    NaN,
+    /// the number is positive or negative infinity
+    Infinite,
+}
+
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumberError::NaN => write!(f, "NaN is not a valid css number"),
+            NumberError::Infinite => write!(f, "infinity is not a valid css number"),
+        }
+    }
+}
+
+impl std::error::Error for NumberError {}
+
+/// check that the number can be written as a css number
+pub fn check_number(value: f64) -> Result<f64, NumberError> {
+    if value.is_nan() {
+        Err(NumberError::NaN)
+    } else if value.is_infinite() {
+        Err(NumberError::Infinite)
+    } else {
+        Ok(value)
+    }
+}
+
+/// the css keyword of a number which is NaN or infinity,
+/// these can only be used inside of a math function such as `calc()`
+pub(crate) fn keyword(value: f64) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value > 0.0 {
+        "infinity"
+    } else {
+        "-infinity"
+    }
+}
+
+/// format the number rounded to at most `precision` decimal places,
+/// without the trailing zeros and with `-0` written as `0`
+/// ```rust
+/// use jss::units::{format_number, NumberError};
+///
+/// assert_eq!(Ok("0.3".to_string()), format_number(0.1 + 0.2, 6));
+/// assert_eq!(Ok("0.667".to_string()), format_number(2.0 / 3.0, 3));
+/// assert_eq!(Ok("0".to_string()), format_number(-0.0, 6));
+/// assert_eq!(Err(NumberError::NaN), format_number(f64::NAN, 6));
+/// ```
+pub fn format_number(value: f64, precision: usize) -> Result<String, NumberError> {
+    let value = check_number(value)?;
+    let mut formatted = format!("{:.*}", precision, value);
+    if formatted.contains('.') {
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.').len();
+        formatted.truncate(trimmed);
+    }
+    if formatted == "-0" {
+        formatted.remove(0);
+    }
+    Ok(formatted)
+}
+
+/// write the number using the precision of the format string, ie: `{:.2}`,
+/// or [`DEFAULT_PRECISION`] when not specified.
+/// NaN and infinity are written as `calc(NaN)` and `calc(infinity)`
+pub(crate) fn write_number(f: &mut fmt::Formatter, value: f64) -> fmt::Result {
+    let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+    match format_number(value, precision) {
+        Ok(formatted) => f.write_str(&formatted),
+        Err(_) => write!(f, "calc({})", keyword(value)),
+    }
+}
+
+/// write the numbers separated by commas, ie: the arguments of `cubic-bezier()` or `matrix()`
+pub(crate) fn write_numbers(f: &mut fmt::Formatter, values: &[f64]) -> fmt::Result {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write_number(f, *value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_zeros_and_negative_zero() {
+        assert_eq!(Ok("10".to_string()), format_number(10.0, 6));
+        assert_eq!(Ok("100".to_string()), format_number(100.0, 0));
+        assert_eq!(Ok("1.5".to_string()), format_number(1.50, 6));
+        assert_eq!(Ok("0".to_string()), format_number(-0.0000001, 6));
+        assert_eq!(Ok("-0.25".to_string()), format_number(-0.25, 6));
+        assert_eq!(Err(NumberError::Infinite), format_number(f64::INFINITY, 6));
+        assert_eq!(
+            Err(NumberError::Infinite),
+            format_number(f64::NEG_INFINITY, 6)
+        );
+    }
+}
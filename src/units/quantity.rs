@@ -0,0 +1,501 @@
+//! typed css dimensions, a number together with its unit
+
+use super::number::{self, NumberError};
+use crate::value::impl_css_value;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg};
+
+/// A unit of a css dimension
+pub trait Unit: Copy + PartialEq + fmt::Display {
+    /// the factor to convert a value in this unit into the canonical unit of its kind,
+    /// `None` for relative units which can not be converted
+    fn canonical_factor(&self) -> Option<f64>;
+}
+
+/// A number together with its unit, ie: `10px`, `45deg`, `200ms`.
+/// A value which is NaN or infinity is written using the css keywords, ie: `calc(infinity * 1px)`,
+/// use [`Quantity::try_new`] and the checked arithmetic such as [`Quantity::checked_div`] to reject them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<U> {
+    value: f64,
+    unit: U,
+}
+
+/// a css `<length>` or `<percentage>`, ie: `10px`, `2em`, `50%`
+pub type Length = Quantity<LengthUnit>;
+/// a css `<percentage>` where a length is not allowed, ie: `75%` in `font-stretch`
+pub type Percentage = Quantity<PercentageUnit>;
+/// a css `<angle>`, ie: `45deg`, `0.5turn`
+pub type Angle = Quantity<AngleUnit>;
+/// a css `<time>`, ie: `200ms`, `1s`
+pub type Time = Quantity<TimeUnit>;
+/// a css `<resolution>`, ie: `2dppx`, `96dpi`
+pub type Resolution = Quantity<ResolutionUnit>;
+/// a css `<frequency>`, ie: `440hz`
+pub type Frequency = Quantity<FrequencyUnit>;
+/// a css `<flex>`, ie: `1fr`
+pub type Flex = Quantity<FlexUnit>;
+
+macro_rules! declare_unit_enum {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident => $css:literal, $factor:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )*
+        }
+
+        impl Unit for $name {
+            fn canonical_factor(&self) -> Option<f64> {
+                match self {
+                    $($name::$variant => $factor,)*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $($name::$variant => write!(f, $css),)*
+                }
+            }
+        }
+    };
+}
+
+declare_unit_enum! {
+    /// the units of `<length>` and `<percentage>`, the absolute units are convertible in px
+    LengthUnit {
+        /// pixels (1px = 1/96th of 1in)
+        Px => "px", Some(1.0);
+        /// inches (1in = 96px = 2.54cm)
+        In => "in", Some(96.0);
+        /// centimeters
+        Cm => "cm", Some(96.0 / 2.54);
+        /// millimeters
+        Mm => "mm", Some(96.0 / 25.4);
+        /// quarter-millimeters (1q = 1/40th of 1cm)
+        Q => "q", Some(96.0 / 101.6);
+        /// points (1pt = 1/72 of 1in)
+        Pt => "pt", Some(96.0 / 72.0);
+        /// picas (1pc = 12pt)
+        Pc => "pc", Some(16.0);
+        /// relative to the font-size of the element
+        Em => "em", None;
+        /// relative to the x-height of the current font
+        Ex => "ex", None;
+        /// relative to the width of the "0" (zero)
+        Ch => "ch", None;
+        /// relative to font-size of the root element
+        Rem => "rem", None;
+        /// relative to 1% of the width of the viewport
+        Vw => "vw", None;
+        /// relative to 1% of the height of the viewport
+        Vh => "vh", None;
+        /// relative to 1% of the viewport's smaller dimension
+        Vmin => "vmin", None;
+        /// relative to 1% of the viewport's larger dimension
+        Vmax => "vmax", None;
+        /// relative to 1% of the viewport in the inline axis
+        Vi => "vi", None;
+        /// relative to 1% of the viewport in the block axis
+        Vb => "vb", None;
+        /// relative to 1% of the width of the small viewport
+        Svw => "svw", None;
+        /// relative to 1% of the height of the small viewport
+        Svh => "svh", None;
+        /// relative to 1% of the small viewport in the inline axis
+        Svi => "svi", None;
+        /// relative to 1% of the small viewport in the block axis
+        Svb => "svb", None;
+        /// relative to 1% of the small viewport's smaller dimension
+        Svmin => "svmin", None;
+        /// relative to 1% of the small viewport's larger dimension
+        Svmax => "svmax", None;
+        /// relative to 1% of the width of the large viewport
+        Lvw => "lvw", None;
+        /// relative to 1% of the height of the large viewport
+        Lvh => "lvh", None;
+        /// relative to 1% of the large viewport in the inline axis
+        Lvi => "lvi", None;
+        /// relative to 1% of the large viewport in the block axis
+        Lvb => "lvb", None;
+        /// relative to 1% of the large viewport's smaller dimension
+        Lvmin => "lvmin", None;
+        /// relative to 1% of the large viewport's larger dimension
+        Lvmax => "lvmax", None;
+        /// relative to 1% of the width of the dynamic viewport
+        Dvw => "dvw", None;
+        /// relative to 1% of the height of the dynamic viewport
+        Dvh => "dvh", None;
+        /// relative to 1% of the dynamic viewport in the inline axis
+        Dvi => "dvi", None;
+        /// relative to 1% of the dynamic viewport in the block axis
+        Dvb => "dvb", None;
+        /// relative to 1% of the dynamic viewport's smaller dimension
+        Dvmin => "dvmin", None;
+        /// relative to 1% of the dynamic viewport's larger dimension
+        Dvmax => "dvmax", None;
+        /// relative to 1% of the width of the query container
+        Cqw => "cqw", None;
+        /// relative to 1% of the height of the query container
+        Cqh => "cqh", None;
+        /// relative to 1% of the inline size of the query container
+        Cqi => "cqi", None;
+        /// relative to 1% of the block size of the query container
+        Cqb => "cqb", None;
+        /// the smaller of cqi and cqb
+        Cqmin => "cqmin", None;
+        /// the larger of cqi and cqb
+        Cqmax => "cqmax", None;
+        /// relative to the line-height of the element
+        Lh => "lh", None;
+        /// relative to the line-height of the root element
+        Rlh => "rlh", None;
+        /// relative to the cap height of the font
+        Cap => "cap", None;
+        /// relative to the width of the "水" glyph of the font
+        Ic => "ic", None;
+        /// percentage
+        Percent => "%", None;
+    }
+}
+
+declare_unit_enum! {
+    /// the unit of `<percentage>`, for the values which can only be a percentage
+    PercentageUnit {
+        /// percentage
+        Percent => "%", None;
+    }
+}
+
+declare_unit_enum! {
+    /// the units of `<angle>`, convertible in degrees
+    AngleUnit {
+        /// degrees
+        Deg => "deg", Some(1.0);
+        /// radians
+        Rad => "rad", Some(180.0 / std::f64::consts::PI);
+        /// gradians (400grad is a full circle)
+        Grad => "grad", Some(0.9);
+        /// turns (1turn is a full circle)
+        Turn => "turn", Some(360.0);
+    }
+}
+
+declare_unit_enum! {
+    /// the units of `<time>`, convertible in milliseconds
+    TimeUnit {
+        /// seconds
+        S => "s", Some(1000.0);
+        /// milliseconds
+        Ms => "ms", Some(1.0);
+    }
+}
+
+declare_unit_enum! {
+    /// the units of `<resolution>`, convertible in dppx
+    ResolutionUnit {
+        /// dots per inch
+        Dpi => "dpi", Some(1.0 / 96.0);
+        /// dots per centimeter
+        Dpcm => "dpcm", Some(2.54 / 96.0);
+        /// dots per px unit
+        Dppx => "dppx", Some(1.0);
+        /// alias of dppx
+        X => "x", Some(1.0);
+    }
+}
+
+declare_unit_enum! {
+    /// the units of `<frequency>`, convertible in hz
+    FrequencyUnit {
+        /// hertz
+        Hz => "hz", Some(1.0);
+        /// kilohertz
+        Khz => "khz", Some(1000.0);
+    }
+}
+
+declare_unit_enum! {
+    /// the unit of `<flex>`, used in grid tracks
+    FlexUnit {
+        /// a fraction of the leftover space in the grid container
+        Fr => "fr", None;
+    }
+}
+
+impl<U: Unit> Quantity<U> {
+    /// create a quantity from the value and unit, use [`Quantity::try_new`] to reject NaN and infinity
+    pub fn new(value: f64, unit: U) -> Self {
+        Self { value, unit }
+    }
+
+    /// create a quantity, rejecting NaN and infinity which are not valid css numbers
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!(Ok(px(1)), Length::try_new(1.0, LengthUnit::Px));
+    /// assert_eq!(Err(NumberError::NaN), Length::try_new(f64::NAN, LengthUnit::Px));
+    /// ```
+    pub fn try_new(value: f64, unit: U) -> Result<Self, NumberError> {
+        number::check_number(value).map(|value| Self::new(value, unit))
+    }
+
+    /// multiply the value, rejecting the result which is NaN or infinity
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!(Ok(px(24)), px(8).checked_mul(3.0));
+    /// assert_eq!(Err(NumberError::Infinite), px(f64::MAX).checked_mul(2.0));
+    /// ```
+    pub fn checked_mul(self, rhs: f64) -> Result<Self, NumberError> {
+        Self::try_new(self.value * rhs, self.unit)
+    }
+
+    /// divide the value, rejecting the result which is NaN or infinity
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!(Ok(rem(0.5)), rem(1).checked_div(2.0));
+    /// assert_eq!(Err(NumberError::Infinite), px(1).checked_div(0.0));
+    /// assert_eq!(Err(NumberError::NaN), px(0).checked_div(0.0));
+    /// ```
+    pub fn checked_div(self, rhs: f64) -> Result<Self, NumberError> {
+        Self::try_new(self.value / rhs, self.unit)
+    }
+
+    /// the numeric value
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// the unit of the value
+    pub fn unit(&self) -> U {
+        self.unit
+    }
+
+    /// format the quantity with the value rounded to at most `precision` decimal places
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!("33.33%", percent(100.0 / 3.0).format(2));
+    /// ```
+    pub fn format(&self, precision: usize) -> String {
+        format!("{:.*}", precision, self)
+    }
+
+    /// convert into the unit, `None` if either unit is relative and the units are not the same
+    /// or if the converted value is not a finite number
+    /// ```rust
+    /// use jss::units::*;
+    ///
+    /// assert_eq!(Some(px(96)), r#in(1).to(LengthUnit::Px));
+    /// assert_eq!(Some(deg(180)), turn(0.5).to(AngleUnit::Deg));
+    /// assert_eq!(None, em(1).to(LengthUnit::Px));
+    /// ```
+    pub fn to(&self, unit: U) -> Option<Self> {
+        if self.unit == unit {
+            return Some(*self);
+        }
+        let from = self.unit.canonical_factor()?;
+        let to = unit.canonical_factor()?;
+        let value = self.value * from / to;
+        // drop the floating point noise of the factors, ie: 1cm is 9.999999999999998mm
+        let rounded = (value * 1e9).round() / 1e9;
+        let value = if rounded.is_finite() { rounded } else { value };
+        Self::try_new(value, unit).ok()
+    }
+
+    /// add `rhs` in the unit of `self`, `None` if `rhs` can not be converted into it
+    pub(crate) fn fold(self, rhs: Self) -> Option<Self> {
+        rhs.to(self.unit)
+            .map(|rhs| Self::new(self.value + rhs.value, self.unit))
+    }
+}
+
+/// The value is rounded to the precision of the format string, ie: `{:.2}`,
+/// or 6 decimal places by default, without the trailing zeros
+/// ```rust
+/// use jss::units::*;
+///
+/// assert_eq!("0.3px", px(0.1 + 0.2).to_string());
+/// assert_eq!("0px", px(-0.0).to_string());
+/// assert_eq!("1.23em", format!("{:.2}", em(1.23456)));
+/// ```
+impl<U: Unit> fmt::Display for Quantity<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.value.is_finite() {
+            return write!(f, "calc({} * 1{})", number::keyword(self.value), self.unit);
+        }
+        number::write_number(f, self.value)?;
+        write!(f, "{}", self.unit)
+    }
+}
+
+impl<U: Unit> Mul<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.value * rhs, self.unit)
+    }
+}
+
+impl<U: Unit> Mul<Quantity<U>> for f64 {
+    type Output = Quantity<U>;
+
+    fn mul(self, rhs: Quantity<U>) -> Quantity<U> {
+        rhs * self
+    }
+}
+
+impl<U: Unit> Div<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.value / rhs, self.unit)
+    }
+}
+
+impl<U: Unit> Neg for Quantity<U> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.unit)
+    }
+}
+
+/// append a string to the quantity, ie: `px(5) + " auto"`
+impl<U: Unit> Add<&str> for Quantity<U> {
+    type Output = String;
+
+    fn add(self, rhs: &str) -> String {
+        self.to_string() + rhs
+    }
+}
+
+impl_css_value!(<U: Unit> Quantity<U>);
+
+/// a percentage can be used where a `<length-percentage>` is expected
+impl From<Percentage> for Length {
+    fn from(percentage: Percentage) -> Self {
+        Length::new(percentage.value, LengthUnit::Percent)
+    }
+}
+
+/// A space separated list of quantities, ie: `10px 20px`
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantityList<U>(pub Vec<Quantity<U>>);
+
+impl<U: Unit> fmt::Display for QuantityList<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, quantity) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            fmt::Display::fmt(quantity, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// append a string to the list, ie: `px([5, 10]) + " auto"`
+impl<U: Unit> Add<&str> for QuantityList<U> {
+    type Output = String;
+
+    fn add(self, rhs: &str) -> String {
+        self.to_string() + rhs
+    }
+}
+
+impl_css_value!(<U: Unit> QuantityList<U>);
+
+/// The numbers which can be turned into a quantity using the unit functions such as `px`,
+/// a single number becomes a [`Quantity`] while arrays and tuples becomes a [`QuantityList`]
+pub trait IntoQuantity<U> {
+    /// the resulting quantity or list of quantities
+    type Output;
+    /// attach the unit to the number
+    fn into_quantity(self, unit: U) -> Self::Output;
+}
+
+/// The primitive numbers which can be used as the value of a quantity
+pub trait Number {
+    /// convert into f64
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl Number for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// a blanket impl instead of one impl per number type,
+// so the output is known even when the type of the number is not inferred yet
+impl<U: Unit, T: Number> IntoQuantity<U> for T {
+    type Output = Quantity<U>;
+
+    fn into_quantity(self, unit: U) -> Quantity<U> {
+        Quantity::new(self.to_f64(), unit)
+    }
+}
+
+impl<U: Unit> IntoQuantity<U> for Quantity<U> {
+    type Output = Quantity<U>;
+
+    fn into_quantity(self, _unit: U) -> Quantity<U> {
+        self
+    }
+}
+
+impl<U, T, const N: usize> IntoQuantity<U> for [T; N]
+where
+    U: Unit,
+    T: IntoQuantity<U, Output = Quantity<U>>,
+{
+    type Output = QuantityList<U>;
+
+    fn into_quantity(self, unit: U) -> QuantityList<U> {
+        QuantityList(self.into_iter().map(|v| v.into_quantity(unit)).collect())
+    }
+}
+
+macro_rules! impl_into_quantity_tuple {
+    (($($T:ident),*) => $($n:tt),*) => {
+        impl<U, $($T),*> IntoQuantity<U> for ($($T),*)
+        where
+            U: Unit,
+            $($T: IntoQuantity<U, Output = Quantity<U>>),*
+        {
+            type Output = QuantityList<U>;
+
+            fn into_quantity(self, unit: U) -> QuantityList<U> {
+                QuantityList(vec![$(self.$n.into_quantity(unit)),*])
+            }
+        }
+    };
+}
+
+impl_into_quantity_tuple!((T, V) => 0, 1);
+impl_into_quantity_tuple!((T, V, W) => 0, 1, 2);
+impl_into_quantity_tuple!((T, V, W, X) => 0, 1, 2, 3);
+impl_into_quantity_tuple!((T, V, W, X, Z) => 0, 1, 2, 3, 4);
@@ -161,6 +161,154 @@ impl fmt::Display for Value {
     }
 }
 
+/// The separator in between the items of a [`List`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// `a b`, ie: `margin: 0 auto`
+    Space,
+    /// `a, b`, ie: `font-family` or the layers of `transition` and `background`
+    Comma,
+    /// `a / b`, ie: `grid-area` or `aspect-ratio`
+    Slash,
+}
+
+impl Separator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Separator::Space => " ",
+            Separator::Comma => ", ",
+            Separator::Slash => " / ",
+        }
+    }
+}
+
+/// A list of values which records its separator,
+/// the items which are `Value::Vec` such as tuples are space separated
+/// ```rust
+/// use jss::prelude::*;
+///
+/// let transition = List::comma([("opacity", ms(200)), ("transform", ms(300))]);
+/// assert_eq!("opacity 200ms, transform 300ms", transition.to_string());
+///
+/// assert_eq!("1 / 3", List::slash([1, 3]).to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct List {
+    /// the separator in between the items
+    pub separator: Separator,
+    /// the items of the list
+    pub items: Vec<Value>,
+}
+
+impl List {
+    /// create a list of the items separated with the separator
+    pub fn new<T: Into<Value>>(separator: Separator, items: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            separator,
+            items: items.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// a space separated list
+    pub fn space<T: Into<Value>>(items: impl IntoIterator<Item = T>) -> Self {
+        Self::new(Separator::Space, items)
+    }
+
+    /// a comma separated list
+    pub fn comma<T: Into<Value>>(items: impl IntoIterator<Item = T>) -> Self {
+        Self::new(Separator::Comma, items)
+    }
+
+    /// a slash separated list
+    pub fn slash<T: Into<Value>>(items: impl IntoIterator<Item = T>) -> Self {
+        Self::new(Separator::Slash, items)
+    }
+
+    /// append an item to the list
+    pub fn push(&mut self, item: impl Into<Value>) {
+        self.items.push(item.into());
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator.as_str())?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Value> for json::JsonValue {
+    fn from(v: Value) -> Self {
+        json::JsonValue::String(v.to_string())
+    }
+}
+
+/// implement the comparison of a typed css value with the `&str` it is written as,
+/// using its `Display` impl
+macro_rules! impl_str_eq {
+    (@impl [$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> PartialEq<&str> for $ty {
+            fn eq(&self, other: &&str) -> bool {
+                self.to_string().as_str() == *other
+            }
+        }
+
+        impl<$($generics)*> PartialEq<$ty> for &str {
+            fn eq(&self, other: &$ty) -> bool {
+                *self == other.to_string().as_str()
+            }
+        }
+    };
+    (<$($g:ident: $bound:path),*> $ty:ty) => {
+        $crate::value::impl_str_eq!(@impl [$($g: $bound),*] $ty);
+    };
+    ($($ty:ty),* $(,)?) => {
+        $(
+            $crate::value::impl_str_eq!(@impl [] $ty);
+        )*
+    };
+}
+
+/// implement the conversion of a typed css value into `Value` and `json::JsonValue`
+/// using its `Display` impl, so it can be used directly in `jss!`,
+/// together with the comparison with `&str` of [`impl_str_eq`].
+/// The generic types are written with their bounds first, ie: `impl_css_value!(<U: Unit> Calc<U>)`
+macro_rules! impl_css_value {
+    (@impl [$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> From<$ty> for $crate::Value {
+            fn from(v: $ty) -> Self {
+                $crate::Value::String(v.to_string())
+            }
+        }
+
+        impl<$($generics)*> From<$ty> for $crate::json::JsonValue {
+            fn from(v: $ty) -> Self {
+                $crate::json::JsonValue::String(v.to_string())
+            }
+        }
+
+        $crate::value::impl_str_eq!(@impl [$($generics)*] $ty);
+    };
+    (<$($g:ident: $bound:path),*> $ty:ty) => {
+        $crate::value::impl_css_value!(@impl [$($g: $bound),*] $ty);
+    };
+    ($($ty:ty),* $(,)?) => {
+        $(
+            $crate::value::impl_css_value!(@impl [] $ty);
+        )*
+    };
+}
+
+pub(crate) use impl_css_value;
+pub(crate) use impl_str_eq;
+
+impl_css_value!(List);
+
 impl From<&String> for Value {
     fn from(v: &String) -> Self {
         Value::String(v.to_string())
@@ -225,3 +373,24 @@ impl_from_tuple!((T, U) => 0, 1);
 impl_from_tuple!((T, U, V) => 0, 1, 2);
 impl_from_tuple!((T, U, V, X) => 0, 1, 2, 3);
 impl_from_tuple!((T, U, V, X, Z) => 0, 1, 2, 3, 4);
+
+/// split at the separators which are not inside parenthesis or brackets,
+/// so `:is(a, b)` and `rgb(1, 2, 3)` are kept intact
+pub(crate) fn split_top_level(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            c if c == separator && depth == 0 => {
+                parts.push(&input[start..i]);
+                start = i + c.len_utf8();
+            }
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ => (),
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
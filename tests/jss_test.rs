@@ -145,3 +145,110 @@ fn test_jss_ns_with_media_query() {
     println!("{}", css);
     assert_eq!(expected, css);
 }
+
+#[test]
+fn test_custom_formatter() {
+    let formatter = Formatter {
+        indent: formatter::Indent::Tabs(1),
+        blank_line_between_rules: true,
+        selector_per_line: true,
+        ..Formatter::pretty()
+    };
+    let css = process_css(
+        None,
+        &json::object! {
+            "h1, h2": {
+                margin: 0,
+            },
+            "@media screen": {
+                ".a": {
+                    display: "none",
+                },
+                ".b": {
+                    display: "block",
+                }
+            },
+        },
+        &Config::from(formatter),
+    );
+
+    let expected = "
+h1,
+h2 {
+\tmargin: 0;
+}
+
+@media screen {
+
+\t.a {
+\t\tdisplay: none;
+\t}
+
+\t.b {
+\t\tdisplay: block;
+\t}
+
+}
+";
+    println!("{}", css);
+    assert_eq!(expected, css);
+}
+
+/// a sink which accepts a number of bytes and then fails
+struct FailingWriter {
+    capacity: usize,
+}
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.len() > self.capacity {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::WriteZero,
+                "the sink is full",
+            ));
+        }
+        self.capacity -= buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_css_io_error() {
+    let mut sink = FailingWriter { capacity: 8 };
+    let error = write_css_io(
+        &mut sink,
+        None,
+        &json::object! {
+            ".layer": { display: "block" },
+        },
+        &Config::compact(),
+    )
+    .unwrap_err();
+    assert_eq!(std::io::ErrorKind::WriteZero, error.kind());
+    assert_eq!("the sink is full", error.to_string());
+}
+
+#[test]
+fn test_no_blank_line_around_nested_rules() {
+    let formatter = Formatter {
+        blank_line_around_nested_rules: false,
+        ..Formatter::pretty()
+    };
+    let css = process_css(
+        None,
+        &json::object! {
+            "@media screen": {
+                ".a": { display: "none" },
+            },
+        },
+        &Config::from(formatter),
+    );
+    assert_eq!(
+        "\n@media screen {\n    .a {\n        display: none;\n    }\n}\n",
+        css
+    );
+}
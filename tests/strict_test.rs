@@ -33,3 +33,74 @@ fn invalid_style_name_if_not_strict_should_work() {
     println!("{}", css);
     assert_eq!(expected, css);
 }
+
+#[test]
+fn custom_properties_are_valid_style_names() {
+    let css = jss!(
+        ":root": {
+            "--accent": "red",
+            color: "var(--accent)",
+        },
+    );
+    assert_eq!(":root{--accent:red;color:var(--accent);}", css);
+}
+
+#[test]
+fn descriptors_in_their_at_rule() {
+    let css = jss!(
+        "@property --angle": {
+            syntax: "'<angle>'",
+            inherits: false,
+            initial_value: "0deg",
+        },
+        "@counter-style thumbs": {
+            system: "cyclic",
+            symbols: "👍",
+            suffix: "' '",
+        },
+        "@page": {
+            size: "A4",
+            margin: "1cm",
+        },
+        "@font-feature-values Font One": {
+            "@styleset": {
+                nice_style: 12,
+            },
+        },
+    );
+    assert_eq!(
+        "@property --angle{syntax:'<angle>';inherits:false;initial-value:0deg;}\
+         @counter-style thumbs{system:cyclic;symbols:👍;suffix:' ';}\
+         @page{size:A4;margin:1cm;}\
+         @font-feature-values Font One{@styleset{nice_style:12;}}",
+        css
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid style name: `syntax` in selector: `.layer`")]
+#[cfg(feature = "strict")]
+fn descriptor_outside_of_its_at_rule() {
+    jss!(
+        ".layer": {
+            syntax: "'<length>'",
+        },
+    );
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn descriptor_outside_of_its_at_rule_if_not_strict_should_work() {
+    let css = jss!(
+        ".layer": {
+            initial_value: 0,
+        },
+        "@counter-style thumbs": {
+            color: "red",
+        },
+    );
+    assert_eq!(
+        ".layer{initial-value:0;}@counter-style thumbs{color:red;}",
+        css
+    );
+}
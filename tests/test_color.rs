@@ -0,0 +1,203 @@
+use jss::prelude::*;
+
+#[test]
+fn test_constructors() {
+    assert_eq!("rgb(10, 20, 30)", Color::rgb(10, 20, 30).to_string());
+    assert_eq!(
+        "rgba(10, 20, 30, 0.25)",
+        Color::rgba(10, 20, 30, 0.25).to_string()
+    );
+    assert_eq!(Color::rgb(255, 0, 0), Color::hsl(0.0, 100.0, 50.0));
+    assert_eq!("rgb(0, 128, 0)", Color::hsl(120.0, 100.0, 25.1).to_string());
+    assert_eq!(Color::rgb(0, 0, 255), Color::hwb(240.0, 0.0, 0.0));
+    assert_eq!(
+        "rgb(128, 128, 128)",
+        Color::hwb(0.0, 50.0, 50.0).to_string()
+    );
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(Ok(Color::rgb(0xaa, 0xbb, 0xcc)), Color::hex("#abc"));
+    assert_eq!(Ok(Color::rgb(0x12, 0x34, 0x56)), Color::hex("123456"));
+    assert_eq!(
+        Ok(Color::rgba(0x12, 0x34, 0x56, 0.0)),
+        Color::hex("#12345600")
+    );
+    assert!(Color::hex("#12").is_err());
+    assert!(Color::hex("#ggg").is_err());
+    assert!(Color::hex("##fff").is_err());
+    assert_eq!("#123456", Color::rgb(0x12, 0x34, 0x56).to_hex());
+    assert_eq!("#12345680", Color::rgba(0x12, 0x34, 0x56, 0.5).to_hex());
+}
+
+#[test]
+fn test_named() {
+    assert_eq!(
+        Some(Color::rgb(0x66, 0x33, 0x99)),
+        Color::named("rebeccapurple")
+    );
+    assert_eq!(
+        Some(Color::rgb(0xf0, 0xf8, 0xff)),
+        Color::named("AliceBlue")
+    );
+    assert_eq!(Some(Color::rgba(0, 0, 0, 0.0)), Color::named("transparent"));
+    assert_eq!(None, Color::named("notacolor"));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Ok(Color::rgb(1, 2, 3)), "rgb(1, 2, 3)".parse());
+    assert_eq!(Ok(Color::rgba(1, 2, 3, 0.5)), "rgba(1, 2, 3, 0.5)".parse());
+    assert_eq!(Ok(Color::rgb(255, 0, 0)), "rgb(100% 0% 0%)".parse());
+    assert_eq!(
+        Ok(Color::hsla(0.0, 100.0, 50.0, 0.5)),
+        "hsla(0, 100%, 50%, .5)".parse()
+    );
+    assert_eq!(
+        Ok(Color::hsl(180.0, 100.0, 50.0)),
+        "hsl(0.5turn 100% 50%)".parse()
+    );
+    assert_eq!(Ok(Color::hwb(240.0, 0.0, 0.0)), "hwb(240 0% 0%)".parse());
+    assert_eq!(Ok(Color::rgb(0, 0, 255)), " Blue ".parse());
+    assert!("rgb(1, 2)".parse::<Color>().is_err());
+    assert_eq!(Ok(Color::rgba(1, 2, 3, 0.5)), "rgb(1 2 3 / 0.5)".parse());
+    assert!("rgb(1 2 3 0.5)".parse::<Color>().is_err());
+    assert!("rgb(1, 2 3)".parse::<Color>().is_err());
+    assert!("rgb(1, 2, 3 / 0.5)".parse::<Color>().is_err());
+    assert!("bluish".parse::<Color>().is_err());
+    assert!("rgb(nan, 0, 0)".parse::<Color>().is_err());
+    assert!("rgb(inf 0 0)".parse::<Color>().is_err());
+    assert!("hsl(infinity 100% 50%)".parse::<Color>().is_err());
+    assert!("oklch(0.7 0.1 NaN)".parse::<Color>().is_err());
+}
+
+#[test]
+fn test_color_in_jss() {
+    let css = jss!(
+        ".layer": {
+            color: Color::named("red").unwrap(),
+            background_color: Color::rgba(0, 0, 0, 0.1),
+        },
+    );
+    assert_eq!(
+        ".layer{color:rgb(255, 0, 0);background-color:rgba(0, 0, 0, 0.1);}",
+        css
+    );
+}
+
+#[test]
+fn test_manipulation() {
+    let brand = Color::rgb(0x33, 0x66, 0x99);
+    assert!(brand.lighten(0.1).red() > brand.red());
+    assert_eq!("rgb(0, 0, 0)", brand.darken(1.0).to_string());
+    assert_eq!("rgb(255, 255, 255)", brand.lighten(1.0).to_string());
+    assert_eq!("rgba(51, 102, 153, 0.5)", brand.with_alpha(0.5).to_string());
+    assert_eq!("rgb(204, 153, 102)", brand.invert().to_string());
+    assert_eq!(brand.to_hex(), brand.complement().complement().to_hex());
+    assert_eq!(
+        brand.to_hex(),
+        brand.mix(&Color::rgb(255, 0, 0), 0.0).to_hex()
+    );
+
+    let gray = brand.desaturate(1.0);
+    assert_eq!(gray.red(), gray.green());
+    assert_eq!(gray.green(), gray.blue());
+    assert_eq!(gray.to_hex(), gray.saturate(0.5).to_hex());
+}
+
+#[test]
+fn test_modern_colors() {
+    assert_eq!("oklch(0.7 0.1 250)", oklch(0.7, 0.1, 250.0).to_string());
+    assert_eq!(
+        "oklch(0.7 0.1 250 / 0.5)",
+        oklch(0.7, 0.1, 250.0).with_alpha(0.5).to_string()
+    );
+    assert_eq!(
+        "rgba(51, 102, 153, 0.5)",
+        ModernColor::from(Color::rgb(51, 102, 153))
+            .with_alpha(0.5)
+            .to_string()
+    );
+    assert_eq!(
+        "color-mix(in srgb, color-mix(in oklch, rgb(255, 0, 0), rgb(0, 0, 255)) 50%, transparent)",
+        color_mix(
+            ColorSpace::Oklch,
+            Color::rgb(255, 0, 0),
+            Color::rgb(0, 0, 255),
+            0.5
+        )
+        .with_alpha(0.5)
+        .to_string()
+    );
+    assert_eq!("oklab(0.5 -0.1 0.1)", oklab(0.5, -0.1, 0.1).to_string());
+    assert_eq!("oklab(0.5 0 0)", oklab(0.5, -0.00001, -0.0).to_string());
+    assert_eq!("lab(50 20 -30)", lab(50.0, 20.0, -30.0).to_string());
+    assert_eq!("lch(50 40 120)", lch(50.0, 40.0, 120.0).to_string());
+
+    assert_eq!(
+        "rgb(255, 255, 255)",
+        oklch(1.0, 0.0, 0.0).fallback().to_string()
+    );
+    assert_eq!(
+        "rgb(255, 255, 255)",
+        lab(100.0, 0.0, 0.0).fallback().to_string()
+    );
+    assert_eq!(
+        "rgb(0, 0, 255)",
+        lch(29.57, 131.2, 301.36).fallback().to_string()
+    );
+    // out of the sRGB gamut colors are reduced in chroma
+    let vivid = oklch(0.7, 0.4, 150.0).fallback();
+    assert!(vivid.green() > vivid.red() && vivid.green() > vivid.blue());
+}
+
+#[test]
+fn test_color_mix() {
+    let mix = color_mix(
+        ColorSpace::Oklch,
+        Color::rgb(255, 0, 0),
+        oklch(0.5, 0.2, 250.0),
+        0.3,
+    );
+    assert_eq!(
+        "color-mix(in oklch, rgb(255, 0, 0), oklch(0.5 0.2 250) 30%)",
+        mix.to_string()
+    );
+    let parsed: Color = mix.to_string().parse().unwrap();
+    assert_eq!(mix.fallback().to_hex(), parsed.to_hex());
+
+    let gray = color_mix(
+        ColorSpace::Srgb,
+        Color::rgb(0, 0, 0),
+        Color::rgb(255, 255, 255),
+        0.5,
+    );
+    assert_eq!("rgb(128, 128, 128)", gray.fallback().to_string());
+    assert_eq!(
+        Ok(gray.fallback()),
+        "color-mix(in srgb, black 50%, white 50%)".parse()
+    );
+}
+
+#[test]
+fn test_color_fallback() {
+    let config = Config {
+        color_fallback: true,
+        ..Config::compact()
+    };
+    let css = process_css(
+        None,
+        &json::object! {
+            ".layer": {
+                color: oklch(1.0, 0.0, 0.0),
+                background_color: "red",
+            }
+        },
+        &config,
+    );
+    assert_eq!(
+        ".layer{color:rgb(255, 255, 255);color:oklch(1 0 0);background-color:red;}",
+        css
+    );
+}
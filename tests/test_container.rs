@@ -0,0 +1,86 @@
+use jss::container::{block_size, inline_size, orientation, style_query};
+use jss::media::{width, Orientation};
+use jss::prelude::*;
+
+#[test]
+fn container_queries() {
+    assert_eq!(
+        "card (inline-size >= 400px)",
+        ContainerQuery::named("card", inline_size().at_least(px(400)))
+    );
+    assert_eq!(
+        "(width > 20em) and (orientation: landscape)",
+        ContainerQuery::new(width().greater_than(em(20))).and(orientation(Orientation::Landscape))
+    );
+    assert_eq!(
+        "@container card (200px <= block-size <= 400px) or style(--compact: true)",
+        ContainerQuery::named("card", block_size().between(px(200), px(400)))
+            .or(style_query("--compact", true))
+            .selector()
+    );
+}
+
+#[test]
+fn namespaced_container_names() {
+    let wide = ContainerQuery::named("card", inline_size().at_least(px(400)));
+    let css = jss_ns_pretty!("product",
+        ".": {
+            container: "card / inline-size",
+        },
+        ".aside": {
+            container_name: "card sidebar",
+        },
+        ".unnamed": {
+            container_name: "none",
+        },
+        [&wide.selector()]: {
+            ".title": { font_size: cqi(5) },
+        },
+        "@container (width > 200px)": {
+            ".title": { display: "none" },
+        },
+    );
+    let expected = r#"
+.product {
+    container: product__card / inline-size;
+}
+.product__aside {
+    container-name: product__card product__sidebar;
+}
+.product__unnamed {
+    container-name: none;
+}
+@container product__card (inline-size >= 400px) {
+
+    .product__title {
+        font-size: 5cqi;
+    }
+
+}
+@container (width > 200px) {
+
+    .product__title {
+        display: none;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn container_names_without_namespace() {
+    let css = jss!(
+        ".card": {
+            container_type: ContainerType::Size,
+            container_name: "card",
+            "@container card (orientation: portrait)": {
+                flex_direction: "column",
+            },
+        },
+    );
+    assert_eq!(
+        ".card{container-type:size;container-name:card;@container card (orientation: portrait){flex-direction:column;}}",
+        css
+    );
+}
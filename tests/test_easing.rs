@@ -0,0 +1,104 @@
+use jss::easing::linear;
+use jss::prelude::*;
+
+#[test]
+fn keywords_and_functions() {
+    assert_eq!("linear", Easing::Linear);
+    assert_eq!("ease-in-out", Easing::EaseInOut);
+    assert_eq!("step-start", Easing::StepStart);
+    assert_eq!(
+        "cubic-bezier(0.68, -0.6, 0.32, 1.6)",
+        Easing::EASE_IN_OUT_BACK
+    );
+    assert_eq!(
+        "cubic-bezier(0.1, 0.7, 1, 0.1)",
+        cubic_bezier(0.1, 0.7, 1.0, 0.1).unwrap()
+    );
+    assert_eq!(
+        "steps(5, jump-none)",
+        steps(5, StepPosition::JumpNone).unwrap()
+    );
+    assert_eq!(
+        "linear(0, 0.25 75%, 1)",
+        linear([0.0.into(), LinearStop::from((0.25, 0.75)), 1.0.into()]).unwrap()
+    );
+}
+
+#[test]
+fn validation() {
+    assert_eq!(
+        Err(EasingError::XOutOfRange(1.5)),
+        cubic_bezier(0.5, 0.0, 1.5, 1.0)
+    );
+    assert_eq!(
+        Err(EasingError::XOutOfRange(-0.1)),
+        cubic_bezier(-0.1, 0.0, 0.5, 1.0)
+    );
+    // y values may overshoot
+    assert!(cubic_bezier(0.5, -2.0, 0.5, 3.0).is_ok());
+    assert_eq!(
+        Err(EasingError::TooFewSteps(0)),
+        steps(0, StepPosition::JumpEnd)
+    );
+    assert_eq!(
+        Err(EasingError::TooFewSteps(1)),
+        steps(1, StepPosition::JumpNone)
+    );
+    assert_eq!(Err(EasingError::TooFewStops), linear([0.5]));
+    assert_eq!(Err(EasingError::NotFinite), linear([0.0, f64::NAN]));
+}
+
+#[test]
+fn eval() {
+    let round = |v: f64| (v * 1e6).round() / 1e6;
+    assert_eq!(0.0, Easing::Ease.eval(0.0));
+    assert_eq!(1.0, Easing::Ease.eval(1.0));
+    // the curve is symmetric
+    assert_eq!(
+        round(1.0 - Easing::EASE_IN_OUT_CUBIC.eval(0.2)),
+        round(Easing::EASE_IN_OUT_CUBIC.eval(0.8))
+    );
+    // cubic-bezier(0.32, 0, 0.67, 0) approximates t^3
+    assert!((Easing::EASE_IN_CUBIC.eval(0.5) - 0.125).abs() < 0.02);
+    assert!(Easing::EASE_OUT_BACK.eval(0.7) > 1.0);
+    assert_eq!(0.6, round(Easing::Linear.eval(0.6)));
+
+    let jump_start = steps(4, StepPosition::JumpStart).unwrap();
+    assert_eq!(0.25, jump_start.eval(0.0));
+    assert_eq!(1.0, jump_start.eval(0.9));
+    let jump_end = steps(4, StepPosition::JumpEnd).unwrap();
+    assert_eq!(0.0, jump_end.eval(0.1));
+    assert_eq!(0.75, jump_end.eval(0.9));
+    assert_eq!(1.0, jump_end.eval(1.0));
+    let jump_none = steps(3, StepPosition::JumpNone).unwrap();
+    assert_eq!(0.0, jump_none.eval(0.2));
+    assert_eq!(0.5, jump_none.eval(0.5));
+    assert_eq!(1.0, jump_none.eval(0.9));
+    let jump_both = steps(3, StepPosition::JumpBoth).unwrap();
+    assert_eq!(0.25, jump_both.eval(0.0));
+    assert_eq!(0.75, jump_both.eval(0.9));
+    assert_eq!(1.0, Easing::StepStart.eval(0.1));
+    assert_eq!(0.0, Easing::StepEnd.eval(0.9));
+
+    // the missing input of 0.5 is placed in between 0% and 100%
+    let piecewise = linear([0.0, 0.8, 1.0]).unwrap();
+    assert_eq!(0.4, piecewise.eval(0.25));
+    assert_eq!(0.9, round(piecewise.eval(0.75)));
+    // the input before a larger input is clamped to it
+    let clamped = linear([(0.0, 0.0), (0.5, 0.6), (0.7, 0.4), (1.0, 1.0)]).unwrap();
+    assert_eq!(0.7, clamped.eval(0.6));
+}
+
+#[test]
+fn easing_in_jss() {
+    let css = jss!(
+        ".drawer": {
+            transition_timing_function: Easing::EASE_OUT_QUART,
+            animation_timing_function: steps(8, StepPosition::JumpEnd).unwrap(),
+        }
+    );
+    assert_eq!(
+        ".drawer{transition-timing-function:cubic-bezier(0.25, 1, 0.5, 1);animation-timing-function:steps(8, jump-end);}",
+        css
+    );
+}
@@ -0,0 +1,146 @@
+use jss::json::JsonValue;
+#[cfg(not(feature = "strict"))]
+use jss::lint::Lint;
+use jss::prelude::*;
+
+fn roboto(weight: u16, style: FontStyle) -> FontFace {
+    FontFace::new("Roboto")
+        .src(FontSource::url(format!("/fonts/roboto-{}.woff2", weight)).format(FontFormat::Woff2))
+        .src(FontSource::url(format!("/fonts/roboto-{}.ttf", weight)).format(FontFormat::TrueType))
+        .weight(weight)
+        .style(style)
+}
+
+#[test]
+fn font_faces_of_the_same_family() {
+    let regular = roboto(400, FontStyle::Normal);
+    let bold = roboto(700, FontStyle::Normal).display(FontDisplay::Fallback);
+    let css = jss_pretty!(
+        "@font-face": FontFace::blocks(&[regular, bold]).unwrap(),
+    );
+    let expected = r#"
+@font-face {
+    font-family: "Roboto";
+    src: url("/fonts/roboto-400.woff2") format("woff2"), url("/fonts/roboto-400.ttf") format("truetype");
+    font-weight: 400;
+    font-style: normal;
+}
+@font-face {
+    font-family: "Roboto";
+    src: url("/fonts/roboto-700.woff2") format("woff2"), url("/fonts/roboto-700.ttf") format("truetype");
+    font-weight: 700;
+    font-style: normal;
+    font-display: fallback;
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn variable_font_descriptors() {
+    let face = FontFace::new("Inter Var")
+        .src(FontSource::local("Inter Var").format(FontFormat::Woff2))
+        .weight_range(100, 900)
+        .stretch_range(percentage(75), percentage(125))
+        .unicode_range(0x0000, 0x00ff)
+        .unicode_range(0x2000, 0x206f)
+        .unicode_range(0x20ac, 0x20ac)
+        .size_adjust(percentage(107))
+        .ascent_override(percentage(90))
+        .variation_settings(r#""opsz" 32"#);
+    let css = jss!("@font-face": JsonValue::try_from(&face).unwrap());
+    assert_eq!(
+        r#"@font-face{font-family:"Inter Var";src:local("Inter Var");font-weight:100 900;font-stretch:75% 125%;unicode-range:U+0-FF, U+2000-206F, U+20AC;size-adjust:107%;ascent-override:90%;font-variation-settings:"opsz" 32;}"#,
+        css
+    );
+}
+
+#[test]
+fn validation() {
+    assert_eq!(Ok(()), roboto(400, FontStyle::Italic).validate());
+    assert_eq!(
+        Err(FontFaceError::MissingSource),
+        FontFace::new("Roboto").validate()
+    );
+    assert_eq!(
+        Err(FontFaceError::WeightOutOfRange(1200)),
+        roboto(1200, FontStyle::Normal).validate()
+    );
+    assert_eq!(
+        Err(FontFaceError::UnorderedRange {
+            descriptor: "font-weight"
+        }),
+        roboto(400, FontStyle::Normal)
+            .weight_range(700, 300)
+            .validate()
+    );
+    assert_eq!(
+        Err(FontFaceError::InvalidUnicodeRange {
+            start: 0x100,
+            end: 0x110000
+        }),
+        roboto(400, FontStyle::Normal)
+            .unicode_range(0x100, 0x110000)
+            .validate()
+    );
+}
+
+#[test]
+fn invalid_font_face_is_not_converted() {
+    assert_eq!(
+        Err(FontFaceError::MissingSource),
+        JsonValue::try_from(FontFace::new("Roboto"))
+    );
+}
+
+#[test]
+fn every_font_face_is_written() {
+    let swap = roboto(400, FontStyle::Normal).display(FontDisplay::Swap);
+    let optional = roboto(400, FontStyle::Normal).display(FontDisplay::Optional);
+    let css = jss!(
+        "@font-face": FontFace::blocks(&[swap, optional.clone(), optional]).unwrap(),
+    );
+    assert_eq!(3, css.matches("@font-face{").count());
+    assert_eq!(
+        Err(FontFaceError::MissingSource),
+        FontFace::blocks(&[roboto(400, FontStyle::Normal), FontFace::new("Roboto")])
+    );
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn descriptors_are_checked_against_the_rule() {
+    let config = Config {
+        lint: Lint {
+            descriptors: true,
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let (css, warnings) = jss::process_css_with_warnings(
+        None,
+        &jss::json::object! {
+            "@font-face": {
+                font_family: "Mono",
+                src: url("/mono.woff2"),
+                color: "red",
+            },
+            ".code": {
+                font_family: "Mono",
+                font_display: "swap",
+            },
+        },
+        &config,
+    );
+    assert_eq!(
+        r#"@font-face{font-family:Mono;src:url("/mono.woff2");color:red;}.code{font-family:Mono;font-display:swap;}"#,
+        css
+    );
+    assert_eq!(
+        vec![
+            "`color` is not a descriptor of `@font-face` in selector: `@font-face`",
+            "descriptor `font-display` can only be used in `@font-face`, `@font-feature-values` in selector: `.code`",
+        ],
+        warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>()
+    );
+}
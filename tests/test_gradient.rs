@@ -0,0 +1,180 @@
+use jss::gradient::{Extent, Shape};
+use jss::json::JsonValue;
+use jss::prelude::*;
+
+#[test]
+fn linear() {
+    assert_eq!(
+        "linear-gradient(45deg, red, blue)",
+        linear_gradient().angle(deg(45)).stop("red").stop("blue")
+    );
+    assert_eq!(
+        "linear-gradient(to bottom right in oklab, rgb(255, 0, 0) 10%, 30%, blue 50% 75%)",
+        linear_gradient()
+            .to(SideOrCorner::BottomRight)
+            .in_space(ColorSpace::Oklab)
+            .stop_at(Color::rgb(255, 0, 0), percent(10))
+            .hint(percent(30))
+            .stop_between("blue", percent(50), percent(75))
+    );
+    assert_eq!(
+        "repeating-linear-gradient(var(--stripe) 0px 10px, transparent 10px 20px)",
+        repeating_linear_gradient()
+            .stop_between(var("stripe"), px(0), px(10))
+            .stop_between("transparent", px(10), px(20))
+    );
+}
+
+#[test]
+fn radial() {
+    assert_eq!(
+        "radial-gradient(red, blue)",
+        radial_gradient().stop("red").stop("blue")
+    );
+    assert_eq!(
+        "radial-gradient(circle closest-side at 50% top, oklch(0.7 0.1 200), transparent)",
+        radial_gradient()
+            .shape(Shape::Circle)
+            .extent(Extent::ClosestSide)
+            .at(percent(50), "top")
+            .stop(oklch(0.7, 0.1, 200.0))
+            .stop("transparent")
+    );
+    assert_eq!(
+        "repeating-radial-gradient(ellipse 40px 20px, red, blue 10px)",
+        repeating_radial_gradient()
+            .shape(Shape::Ellipse)
+            .extent(Extent::Radii(px(40), px(20)))
+            .stop("red")
+            .stop_at("blue", px(10))
+    );
+}
+
+#[test]
+fn conic() {
+    assert_eq!(
+        "conic-gradient(from 90deg at 25% 75% in oklch longer hue, red, red)",
+        conic_gradient()
+            .from(deg(90))
+            .at(percent(25), percent(75))
+            .in_hue(ColorSpace::Oklch, HueInterpolation::Longer)
+            .stop("red")
+            .stop("red")
+    );
+    assert_eq!(
+        "repeating-conic-gradient(black 0deg 0.25turn, white 0.25turn 0.5turn)",
+        repeating_conic_gradient()
+            .stop_between("black", deg(0), turn(0.25))
+            .stop_between("white", turn(0.25), turn(0.5))
+    );
+    assert_eq!(
+        "conic-gradient(red 0%, blue 25%, green 90deg 50%)",
+        conic_gradient()
+            .stop_at("red", percent(0))
+            .stop_at("blue", percent(25))
+            .stop_between("green", deg(90), percent(50))
+    );
+}
+
+#[test]
+fn validation() {
+    assert_eq!(
+        Err(GradientError::TooFewStops),
+        linear_gradient().stop("red").validate()
+    );
+    assert_eq!(
+        Err(GradientError::MisplacedHint),
+        linear_gradient()
+            .stop("red")
+            .hint(percent(10))
+            .hint(percent(20))
+            .stop("blue")
+            .validate()
+    );
+    assert_eq!(
+        Err(GradientError::UnorderedStops {
+            previous: "50%".to_string(),
+            next: "20%".to_string()
+        }),
+        linear_gradient()
+            .stop_at("red", percent(50))
+            .stop_at("blue", percent(20))
+            .validate()
+    );
+    // 1in is 96px
+    assert!(linear_gradient()
+        .stop_at("red", r#in(1))
+        .stop_at("blue", px(90))
+        .validate()
+        .is_err());
+    // the positions in different kinds of units depends on the size of the box
+    assert_eq!(
+        Ok(()),
+        linear_gradient()
+            .stop_at("red", percent(50))
+            .stop_at("blue", px(20))
+            .validate()
+    );
+    assert!(conic_gradient()
+        .stop_at("red", turn(0.5))
+        .stop_at("blue", deg(90))
+        .validate()
+        .is_err());
+    assert_eq!(
+        Err(GradientError::HueInRectangularSpace(ColorSpace::Oklab)),
+        linear_gradient()
+            .in_hue(ColorSpace::Oklab, HueInterpolation::Longer)
+            .stop("red")
+            .stop("blue")
+            .validate()
+    );
+    assert_eq!(
+        Err(GradientError::UnknownColor("not-a-color".to_string())),
+        linear_gradient().stop("red").stop("not-a-color").validate()
+    );
+    assert_eq!(
+        Ok(()),
+        linear_gradient()
+            .stop("currentColor")
+            .stop(Color::rgb(0, 0, 255))
+            .validate()
+    );
+    // 50% of a turn is 180deg
+    assert!(conic_gradient()
+        .stop_at("red", percent(50))
+        .stop_at("blue", deg(90))
+        .validate()
+        .is_err());
+    assert_eq!(
+        Err(GradientError::InvalidPosition("10px".to_string())),
+        conic_gradient()
+            .stop_at("red", px(10))
+            .stop("blue")
+            .validate()
+    );
+    let value: Result<Value, _> = linear_gradient().stop("red").try_into();
+    assert!(value.is_err());
+}
+
+#[test]
+fn gradient_in_jss() {
+    let css = jss!(
+        ".hero": {
+            background_image: JsonValue::try_from(
+                linear_gradient().to(SideOrCorner::Top).stop("white").stop("black")
+            ).unwrap(),
+        }
+    );
+    assert_eq!(
+        ".hero{background-image:linear-gradient(to top, white, black);}",
+        css
+    );
+}
+
+#[test]
+fn invalid_gradient_is_not_converted() {
+    assert_eq!(
+        Err(GradientError::TooFewStops),
+        JsonValue::try_from(linear_gradient().stop("white"))
+    );
+}
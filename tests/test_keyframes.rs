@@ -0,0 +1,136 @@
+use jss::json::{object, JsonValue};
+use jss::lint::Lint;
+use jss::prelude::*;
+
+fn pulse() -> Keyframes {
+    Keyframes::new("pulse")
+        .frame(Offset::From, object! { opacity: 1, transform: scale(1.0) })
+        .frame(0.0, object! { opacity: 0.5 })
+        .frame(0.125, object! { transform: scale(1.1) })
+        .frame(1.0, object! { opacity: 1 })
+}
+
+#[test]
+fn offsets_and_merging() {
+    let css = jss!(
+        [pulse().selector()]: JsonValue::try_from(pulse()).unwrap(),
+    );
+    assert_eq!(
+        "@keyframes pulse{from{opacity:0.5;transform:scale(1);}12.5%{transform:scale(1.1);}100%{opacity:1;}}",
+        css
+    );
+}
+
+#[test]
+fn validation() {
+    assert_eq!(Ok(()), pulse().validate());
+    assert_eq!(
+        Err(KeyframesError::OutOfRange(1.5)),
+        Keyframes::new("a")
+            .frame(0.0, object! {})
+            .frame(1.5, object! {})
+            .validate()
+    );
+    assert_eq!(
+        Err(KeyframesError::OutOfRange(-0.5)),
+        JsonValue::try_from(Keyframes::new("a").frame(-0.5, object! {}))
+    );
+}
+
+#[test]
+fn frames_are_sorted_and_merged() {
+    let blink = Keyframes::new("blink")
+        .frame(Offset::To, object! { opacity: 1 })
+        .frame(0.5, object! { opacity: 0 })
+        .frame(Offset::From, object! { opacity: 1 })
+        .frame(0.5, object! { color: "red" });
+    let css = jss!(
+        [blink.selector()]: JsonValue::try_from(&blink).unwrap(),
+    );
+    assert_eq!(
+        "@keyframes blink{from{opacity:1;}50%{opacity:0;color:red;}to{opacity:1;}}",
+        css
+    );
+}
+
+#[test]
+fn keyframes_in_namespace() {
+    let slide = Keyframes::new("slide")
+        .frame(
+            Offset::From,
+            object! { transform: translate_x(percent(-100)) },
+        )
+        .frame(Offset::To, object! { transform: translate_x(px(0)) });
+    let css = jss_ns_pretty!("drawer",
+        ".panel": {
+            animation_name: slide.name(),
+            [slide.selector()]: JsonValue::try_from(&slide).unwrap(),
+        },
+        ".panel.open": {
+            display: "block",
+        }
+    );
+    let expected = r#"
+.drawer__panel {
+    animation-name: slide;
+}
+@keyframes slide {
+
+    from {
+        transform: translateX(-100%);
+    }
+    to {
+        transform: translateX(0px);
+    }
+
+}
+.drawer__panel.drawer__open {
+    display: block;
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn keyframes_inside_media_query_stays_nested() {
+    let css = jss!(
+        "@media (prefers-reduced-motion: no-preference)": {
+            "@keyframes spin": {
+                from: { transform: rotate(deg(0)) },
+                to: { transform: rotate(turn(1)) },
+            }
+        }
+    );
+    assert_eq!(
+        "@media (prefers-reduced-motion: no-preference){@keyframes spin{from{transform:rotate(0deg);}to{transform:rotate(1turn);}}}",
+        css
+    );
+}
+
+#[test]
+fn not_animatable_lint() {
+    let config = Config {
+        lint: Lint {
+            animatable: true,
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let json = object! {
+        "@keyframes fade": {
+            from: { opacity: 0, transition_duration: "1s", animation_timing_function: "ease-in" },
+            to: { opacity: 1, "will-change": "opacity" },
+        },
+        ".fade": {
+            transition_duration: "1s",
+        }
+    };
+    let (_, warnings) = process_css_with_warnings(None, &json, &config);
+    assert_eq!(
+        vec![
+            "property `transition-duration` at keyframe `from` can not be animated in selector: `@keyframes fade`",
+            "property `will-change` at keyframe `to` can not be animated in selector: `@keyframes fade`",
+        ],
+        warnings.iter().map(ToString::to_string).collect::<Vec<_>>()
+    );
+}
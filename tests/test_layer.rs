@@ -0,0 +1,142 @@
+use jss::json::object;
+use jss::prelude::*;
+
+fn layers() -> Layers {
+    Layers::new(["reset", "base", "components", "utilities"])
+        .add(
+            "utilities",
+            None,
+            object! { ".hidden": { display: "none" } },
+        )
+        .add(
+            "components",
+            Some("button"),
+            object! {
+                ".": { padding: px(8) },
+                ".icon": { width: px(16) },
+            },
+        )
+        .add("reset", None, object! { "*": { box_sizing: "border-box" } })
+        .add(
+            "components",
+            Some("card"),
+            object! {
+                ".": { padding: px(16) },
+                "@md": {
+                    ".": { padding: px(24) },
+                },
+            },
+        )
+}
+
+#[test]
+fn layer_order_is_declared_first() {
+    assert_eq!(
+        vec!["reset", "base", "components", "utilities"],
+        layers().order().collect::<Vec<_>>()
+    );
+    let expected = r#"
+@layer reset, base, components, utilities;
+@layer reset {
+
+    * {
+        box-sizing: border-box;
+    }
+
+}
+@layer components {
+
+    .button {
+        padding: 8px;
+    }
+    .button__icon {
+        width: 16px;
+    }
+    .card {
+        padding: 16px;
+    }
+    @media (width >= 768px) {
+
+        .card {
+            padding: 24px;
+        }
+
+    }
+
+}
+@layer utilities {
+
+    .hidden {
+        display: none;
+    }
+
+}
+"#;
+    assert_eq!(expected, layers().process_css(&Config::pretty()));
+}
+
+#[test]
+fn undeclared_layer_is_last() {
+    let css = Layers::new(["base"])
+        .add("overrides", Some("menu"), object! { ".": { color: "red" } })
+        .process_css(&Config::compact());
+    assert_eq!(
+        "@layer base, overrides;@layer overrides{.menu{color:red;}}",
+        css
+    );
+}
+
+#[test]
+fn empty_layers() {
+    assert_eq!(
+        "",
+        Layers::new(Vec::<String>::new()).process_css(&Config::compact())
+    );
+    assert_eq!("\n\n", Layers::default().process_css(&Config::pretty()));
+}
+
+#[test]
+fn statement_at_rules() {
+    let css = jss_ns!("app",
+        "@import": url("theme.css"),
+        "@layer": "reset, base",
+        "@layer base": {
+            ".": { margin: 0 },
+            "@layer": ["typography"],
+            "@layer typography": {
+                "h1": { font_size: rem(2) },
+            },
+        },
+        "@layer reset.tokens": null,
+    );
+    assert_eq!(
+        r#"@import url("theme.css");@layer reset, base;@layer base{.app{margin:0;}@layer typography;@layer typography{h1{font-size:2rem;}}}@layer reset.tokens;"#,
+        css
+    );
+}
+
+#[test]
+fn warnings_of_the_fragments() {
+    let config = Config {
+        lint: jss::lint::Lint {
+            at_rules: true,
+            ..Default::default()
+        },
+        ..Config::compact()
+    };
+    let (css, warnings) = Layers::new(["base"])
+        .add(
+            "base",
+            None,
+            object! { "@mdia print": { "a": { color: "black" } } },
+        )
+        .process_css_with_warnings(&config);
+    assert_eq!("@layer base;@layer base{@mdia print{a{color:black;}}}", css);
+    assert_eq!(
+        vec!["@mdia print"],
+        warnings
+            .iter()
+            .map(|w| w.selector.as_str())
+            .collect::<Vec<_>>()
+    );
+}
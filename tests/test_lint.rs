@@ -0,0 +1,112 @@
+use jss::lint::{Lint, WcagLevel};
+use jss::prelude::*;
+
+#[test]
+fn test_contrast_ratio() {
+    let black = Color::rgb(0, 0, 0);
+    let white = Color::rgb(255, 255, 255);
+    assert_eq!(21.0, black.contrast_ratio(&white));
+    assert_eq!(21.0, white.contrast_ratio(&black));
+    assert_eq!(1.0, white.contrast_ratio(&white));
+    let gray = Color::rgb(0x76, 0x76, 0x76);
+    assert!(gray.contrast_ratio(&white) > WcagLevel::Aa.min_ratio());
+    assert!(gray.contrast_ratio(&white) < WcagLevel::Aaa.min_ratio());
+}
+
+#[test]
+fn test_contrast_lint() {
+    let config = Config {
+        lint: Lint {
+            contrast: Some(WcagLevel::Aa),
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let (css, warnings) = process_css_with_warnings(
+        None,
+        &json::object! {
+            ".ok": {
+                color: "black",
+                background_color: "white",
+            },
+            ".low": {
+                color: "#999",
+                background_color: "white",
+            },
+            ".translucent": {
+                color: Color::rgba(0, 0, 0, 0.2),
+                background_color: "white",
+            },
+            ".unknown": {
+                color: "var(--fg)",
+                background_color: "white",
+            },
+        },
+        &config,
+    );
+    assert!(css.starts_with(".ok{color:black;background-color:white;}"));
+    let selectors: Vec<&str> = warnings.iter().map(|w| w.selector.as_str()).collect();
+    assert_eq!(vec![".low", ".translucent"], selectors);
+}
+
+#[test]
+fn test_warnings_use_the_rendered_selector() {
+    let json = json::object! {
+        ".low": {
+            color: "#999",
+            background_color: "white",
+        },
+        "@unknown": {
+            ".low": { display: "none" },
+        },
+    };
+    // the checks are off by default
+    let config = Config::compact();
+    let (_, warnings) = process_css_with_warnings(Some("card"), &json, &config);
+    assert!(warnings.is_empty());
+
+    let config = Config {
+        lint: Lint {
+            contrast: Some(WcagLevel::Aa),
+            at_rules: true,
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let (_, warnings) = process_css_with_warnings(Some("card"), &json, &config);
+    let selectors: Vec<&str> = warnings.iter().map(|w| w.selector.as_str()).collect();
+    assert_eq!(vec![".card__low", "@unknown"], selectors);
+}
+
+#[test]
+fn test_known_at_rules() {
+    let config = Config {
+        lint: Lint {
+            at_rules: true,
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let json = json::object! {
+        "@page": {
+            "@top-left": { content: "\"title\"" },
+            "@bottom-right-corner": { content: "counter(page)" },
+        },
+        "@scope (.card)": {
+            "img": { display: "block" },
+        },
+        ".card": {
+            "@starting-style": { opacity: 0 },
+        },
+        "@-webkit-keyframes spin": {
+            to: { transform: "rotate(1turn)" },
+        },
+        "@view-transition": { navigation: "auto" },
+        "@-webkit-unknown": {
+            ".card": { display: "none" },
+        },
+    };
+    let (_, warnings) = process_css_with_warnings(None, &json, &config);
+    let selectors: Vec<&str> = warnings.iter().map(|w| w.selector.as_str()).collect();
+    assert_eq!(vec!["@-webkit-unknown"], selectors);
+}
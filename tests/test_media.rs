@@ -0,0 +1,221 @@
+use jss::lint::Lint;
+use jss::media::{
+    any_pointer, height, hover, prefers_color_scheme, resolution, width, BreakpointError,
+    ColorScheme, Hover, Pointer,
+};
+use jss::prelude::*;
+
+#[test]
+fn features() {
+    assert_eq!("(width >= 600px)", width().at_least(px(600)).to_string());
+    assert_eq!("(height < 30em)", height().less_than(em(30)).to_string());
+    assert_eq!(
+        "(resolution > 1dppx)",
+        resolution().greater_than(dppx(1)).to_string()
+    );
+    assert_eq!("(width: 1024px)", width().equals(px(1024)).to_string());
+    assert_eq!(
+        "(prefers-color-scheme: dark)",
+        prefers_color_scheme(ColorScheme::Dark).to_string()
+    );
+    assert_eq!(
+        "(any-pointer: fine)",
+        any_pointer(Pointer::Fine).to_string()
+    );
+}
+
+#[test]
+fn combined_conditions() {
+    let condition = hover(Hover::Hover)
+        .and(any_pointer(Pointer::Fine))
+        .and(width().at_least(px(600)).or(!resolution().at_most(dppx(1))));
+    assert_eq!(
+        "(hover: hover) and (any-pointer: fine) and ((width >= 600px) or (not (resolution <= 1dppx)))",
+        condition.to_string()
+    );
+}
+
+#[test]
+fn media_type_with_or_condition() {
+    let query = MediaQuery::screen().and(hover(Hover::None).or(any_pointer(Pointer::Coarse)));
+    assert_eq!("screen and ((hover: none) or (any-pointer: coarse))", query);
+    assert_eq!(
+        "not print and (width >= 10cm)",
+        !MediaQuery::print().and(width().at_least(cm(10)))
+    );
+    // negating twice cancels out
+    assert_eq!("(hover: none)", !!MediaQuery::from(hover(Hover::None)));
+}
+
+#[test]
+fn default_breakpoints() {
+    let css = jss_ns_pretty!("card",
+        ".": { padding: px(8) },
+        "@md": {
+            ".": { padding: px(16) },
+        },
+        "@lg": {
+            ".": { padding: px(24) },
+        },
+    );
+    let expected = r#"
+.card {
+    padding: 8px;
+}
+@media (width >= 768px) {
+
+    .card {
+        padding: 16px;
+    }
+
+}
+@media (width >= 1024px) {
+
+    .card {
+        padding: 24px;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn nested_breakpoint_and_unknown_at_rule() {
+    let css = jss!(
+        ".grid": {
+            display: "grid",
+            "@sm": { grid_template_columns: "1fr 1fr" },
+        },
+        "@unknown": {
+            ".grid": { gap: px(4) },
+        },
+    );
+    assert_eq!(
+        ".grid{display:grid;@media (width >= 640px){grid-template-columns:1fr 1fr;}}@unknown{.grid{gap:4px;}}",
+        css
+    );
+}
+
+#[test]
+fn custom_breakpoints() {
+    let breakpoints = Breakpoints::default()
+        .with("md", width().at_least(px(800)))
+        .and_then(|breakpoints| breakpoints.with("dark", prefers_color_scheme(ColorScheme::Dark)))
+        .unwrap();
+    assert_eq!(
+        vec!["sm", "md", "lg", "xl", "dark"],
+        breakpoints.iter().map(|(name, _)| name).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some("@media (width >= 800px)".to_string()),
+        breakpoints.resolve("@md")
+    );
+    assert_eq!(None, breakpoints.resolve("@media print"));
+    assert_eq!(
+        Err(BreakpointError::AtRule("media".to_string())),
+        Breakpoints::default().with("media", width().at_least(px(800)))
+    );
+    assert!(Breakpoints::default()
+        .with("-webkit-keyframes", width().at_least(px(800)))
+        .is_err());
+    assert_eq!(None, Breakpoints::empty().get("md"));
+}
+
+#[test]
+fn responsive_values() {
+    let css = jss_ns_pretty!("card",
+        ".": {
+            display: "flex",
+            padding: { base: px(8), md: px(16), lg: px(24) },
+            font_size: { lg: rem(1.25), base: rem(1) },
+        },
+        ".title": {
+            margin: 0,
+        },
+    );
+    let expected = r#"
+.card {
+    display: flex;
+    padding: 8px;
+    font-size: 1rem;
+}
+@media (width >= 768px) {
+
+    .card {
+        padding: 16px;
+    }
+
+}
+@media (width >= 1024px) {
+
+    .card {
+        padding: 24px;
+        font-size: 1.25rem;
+    }
+
+}
+.card__title {
+    margin: 0;
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn responsive_values_inside_media_and_custom_breakpoints() {
+    let config = Config {
+        breakpoints: Breakpoints::empty()
+            .with("wide", width().at_least(px(1200)))
+            .unwrap(),
+        ..Config::compact()
+    };
+    let css = jss::process_css(
+        Some("page"),
+        &jss::json::object! {
+            "@media print": {
+                ".body": { columns: { base: 1, wide: 2 } },
+            },
+            // `md` is not a breakpoint of this config so this is a nested rule
+            ".aside": { md: { display: "none" } },
+        },
+        &config,
+    );
+    assert_eq!(
+        "@media print{.page__body{columns:1;}@media (width >= 1200px){.page__body{columns:2;}}}\
+         .page__aside{md{display:none;}}",
+        css
+    );
+}
+
+#[test]
+fn responsive_values_are_not_expanded_in_keyframes() {
+    let config = Config {
+        lint: Lint {
+            responsive: true,
+            ..Lint::default()
+        },
+        ..Config::compact()
+    };
+    let (css, warnings) = jss::process_css_with_warnings(
+        None,
+        &jss::json::object! {
+            "@keyframes grow": {
+                from: { width: px(0) },
+                to: { width: { base: px(100), md: px(200) }, opacity: 1 },
+            },
+        },
+        &config,
+    );
+    assert_eq!(
+        "@keyframes grow{from{width:0px;}to{width:100px;opacity:1;}}",
+        css
+    );
+    assert_eq!(
+        vec!["responsive value of `width` at keyframe `to` can not be expanded into `@media` blocks in selector: `@keyframes grow`"],
+        warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+    );
+}
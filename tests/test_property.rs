@@ -0,0 +1,104 @@
+use jss::json::JsonValue;
+use jss::prelude::*;
+
+#[test]
+fn registered_custom_property() {
+    let offset = CustomProperty::new("--offset", Syntax::Length)
+        .inherits(false)
+        .initial_value(px(0));
+    let css = jss_pretty!(
+        [&offset.selector()]: JsonValue::try_from(&offset).unwrap(),
+        ".drawer": {
+            translate: offset.var(),
+            transition: [[offset.name(), ms(200)]],
+        },
+        ".drawer.open": {
+            [offset.name()]: px(240),
+        },
+    );
+    let expected = r#"
+@property --offset {
+    syntax: "<length>";
+    inherits: false;
+    initial-value: 0px;
+}
+.drawer {
+    translate: var(--offset);
+    transition: --offset 200ms;
+}
+.drawer.open {
+    --offset: 240px;
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn universal_syntax_has_no_initial_value() {
+    let theme = CustomProperty::new("theme", Syntax::Universal);
+    assert_eq!("--theme", theme.name());
+    assert_eq!("var(--theme, light)", theme.var().or("light"));
+    let css = jss!([&theme.selector()]: JsonValue::try_from(theme).unwrap());
+    assert_eq!(r#"@property --theme{syntax:"*";inherits:true;}"#, css);
+}
+
+#[test]
+fn initial_value_matches_the_syntax() {
+    let valid = [
+        (Syntax::Length, "0"),
+        (Syntax::Length, "1.5pt"),
+        (Syntax::LengthPercentage, "50%"),
+        (Syntax::Number, "0.5"),
+        (Syntax::Integer, "3"),
+        (Syntax::Angle, "0.25turn"),
+        (Syntax::Time, "200ms"),
+        (Syntax::Resolution, "2dppx"),
+        (Syntax::Color, "#ff000080"),
+        (Syntax::Color, "oklch(70% 0.1 200)"),
+        (Syntax::Image, "linear-gradient(red, blue)"),
+        (Syntax::TransformFunction, "rotate(45deg)"),
+        (Syntax::TransformFunction, "translate3d(0px, 0px, 10px)"),
+        (Syntax::CustomIdent, "compact"),
+    ];
+    for (syntax, value) in valid {
+        let property = CustomProperty::new("x", syntax).initial_value(value);
+        assert_eq!(Ok(()), property.validate(), "{} {}", syntax, value);
+    }
+    assert_eq!(
+        Err(CustomPropertyError::MissingInitialValue),
+        CustomProperty::new("x", Syntax::Color).validate()
+    );
+    let invalid = [
+        (Syntax::Length, "1em"),
+        (Syntax::Length, "10vw"),
+        (Syntax::Percentage, "10px"),
+        (Syntax::Integer, "1.5"),
+        (Syntax::Angle, "45"),
+        (Syntax::Color, "not-a-color"),
+        (Syntax::Url, "a.png"),
+        (Syntax::TransformFunction, "var(--x)"),
+        (Syntax::TransformFunction, "calc(1px + 2px)"),
+        (Syntax::TransformFunction, "rgb(0, 0, 0)"),
+        (Syntax::CustomIdent, "inherit"),
+    ];
+    for (syntax, value) in invalid {
+        let property = CustomProperty::new("x", syntax).initial_value(value);
+        assert_eq!(
+            Err(CustomPropertyError::InvalidInitialValue {
+                syntax,
+                value: value.to_string()
+            }),
+            property.validate()
+        );
+    }
+}
+
+#[test]
+fn invalid_custom_property_is_not_converted() {
+    let size = CustomProperty::new("size", Syntax::Length).initial_value(em(1));
+    let error = JsonValue::try_from(&size).unwrap_err();
+    assert_eq!(
+        "initial value `1em` is not a computationally independent <length>",
+        error.to_string()
+    );
+}
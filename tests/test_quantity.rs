@@ -0,0 +1,159 @@
+use jss::prelude::*;
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(px(14), px(10) + px(4));
+    assert_eq!(px(6), px(10) - px(4));
+    assert_eq!(rem(0.5), rem(1) / 2.0);
+    assert_eq!(px(24), px(8) * 3.0);
+    assert_eq!(px(24), 3.0 * px(8));
+    assert_eq!(px(-8), -px(8));
+    assert_eq!("14px", (px(10) + px(4)).to_string());
+}
+
+#[test]
+fn test_arithmetic_converts_absolute_units() {
+    assert_eq!(px(192), px(96) + r#in(1));
+    assert_eq!(r#in(2), r#in(1) + px(96));
+    assert_eq!(ms(1500), ms(500) + s(1));
+    assert_eq!(deg(270), deg(180) + turn(0.25));
+}
+
+#[test]
+fn test_calc() {
+    assert_eq!("calc(100% - 20px)", calc(percent(100)) - px(20));
+    assert_eq!("calc(100% + 20px)", calc(percent(100)) + px(20));
+    assert_eq!("calc(100% - 20px)", calc(percent(100)) - px(10) - px(10));
+    assert_eq!(
+        "calc(100% - 1em + 96px)",
+        calc(percent(100)) - em(1) + px(96)
+    );
+    assert_eq!("calc(200% - 40px)", (calc(percent(100)) - px(20)) * 2.0);
+    assert_eq!("calc(-50% + 10px)", -(calc(percent(100)) - px(20)) / 2.0);
+    assert_eq!("100%", calc(percent(100)) + px(20) - px(20) + percent(0));
+    assert_eq!(Some(px(4)), (calc(px(10)) - px(6)).as_quantity());
+    assert_eq!(None, (calc(px(10)) - em(6)).as_quantity());
+}
+
+#[test]
+fn test_same_unit_arithmetic_folds_into_a_quantity() {
+    let width: Length = (px(10) + px(4) - px(2)).as_quantity().unwrap();
+    assert_eq!(px(12), width);
+    assert_eq!(Some(r#in(0.125)), width.to(LengthUnit::In));
+    assert_eq!(
+        Some(s(2)),
+        (ms(1500) + ms(500))
+            .as_quantity()
+            .and_then(|time| time.to(TimeUnit::S))
+    );
+}
+
+#[test]
+fn test_mixed_units_are_written_in_calc() {
+    assert_eq!("calc(100% - 20px)", percent(100) - px(20));
+    assert_eq!("calc(100% - 20px)", percent(100) - px(10) - px(10));
+    assert_eq!("calc(1em + 2px)", em(1) + px(1) + px(1));
+    assert_eq!("100%", percent(100) + px(20) - px(20));
+    assert_eq!(None, (percent(100) - px(20)).as_quantity());
+}
+
+#[test]
+fn test_math_functions() {
+    assert_eq!("min(50%, 300px)", min(percent(50), px(300)));
+    assert_eq!("max(10vh, 100px + 2em)", max(vh(10), calc(px(100)) + em(2)));
+    assert_eq!(
+        "calc(100% - max(10px, 2em))",
+        calc(percent(100)) - max(px(10), em(2))
+    );
+    assert_eq!(
+        "calc(2 * min(10px, 1em) - 5px)",
+        min(px(10), em(1)) * 2.0 - px(5)
+    );
+    assert_eq!(
+        "clamp(1rem, min(5vw, 3rem), 4rem)",
+        clamp(rem(1), min(vw(5), rem(3)), rem(4))
+    );
+    assert_eq!("min(45deg, 350deg)", min(deg(45), deg(360) - deg(10)));
+}
+
+#[test]
+fn test_conversion() {
+    assert_eq!(Some(px(96)), r#in(1).to(LengthUnit::Px));
+    assert_eq!(Some(mm(10)), cm(1).to(LengthUnit::Mm));
+    assert_eq!(Some(pt(12)), pc(1).to(LengthUnit::Pt));
+    assert_eq!(
+        Some(q(40)),
+        cm(1)
+            .to(LengthUnit::Q)
+            .map(|l| Length::new(l.value().round(), l.unit()))
+    );
+    assert_eq!(Some(grad(400)), turn(1).to(AngleUnit::Grad));
+    assert_eq!(Some(s(0.25)), ms(250).to(TimeUnit::S));
+    assert_eq!(
+        Some(Resolution::new(2.0, ResolutionUnit::Dppx)),
+        Resolution::new(192.0, ResolutionUnit::Dpi).to(ResolutionUnit::Dppx)
+    );
+    assert_eq!(None, percent(10).to(LengthUnit::Px));
+    assert_eq!(Some(em(2)), em(2).to(LengthUnit::Em));
+}
+
+#[test]
+fn test_quantity_in_jss() {
+    let spacing = px(8);
+    let css = jss!(
+        ".layer": {
+            padding: spacing * 2.0,
+            margin: (spacing / 2.0) + " auto",
+        },
+    );
+    assert_eq!(".layer{padding:16px;margin:4px auto;}", css);
+}
+
+#[test]
+fn number_formatting() {
+    assert_eq!("0.3px", px(0.1 + 0.2));
+    assert_eq!("0px", px(-0.0));
+    assert_eq!("0.1em", em(0.1f32));
+    assert_eq!("33.333333%", percent(100.0 / 3.0));
+    assert_eq!(
+        "33.3% 66.7%",
+        format!("{:.1}", percent([100.0 / 3.0, 200.0 / 3.0]))
+    );
+    assert_eq!(
+        "calc(33.33% - 0.67px)",
+        format!("{:.2}", calc(percent(100.0 / 3.0)) - px(2.0 / 3.0))
+    );
+    assert_eq!(
+        Err(NumberError::NaN),
+        Length::try_new(f64::NAN, LengthUnit::Px)
+    );
+    assert_eq!(
+        Err(NumberError::Infinite),
+        Time::try_new(f64::INFINITY, TimeUnit::Ms)
+    );
+    assert_eq!("33.33%", percent(100.0 / 3.0).format(2));
+}
+
+#[test]
+fn nan_and_infinity_are_written_with_the_css_keywords() {
+    assert_eq!("calc(NaN * 1px)", px(f64::NAN));
+    assert_eq!("calc(infinity * 1px)", px(1) / 0.0);
+    assert_eq!("calc(-infinity * 1%)", percent(-1) / 0.0);
+    assert_eq!(
+        "calc(100% - calc(infinity * 1px))",
+        percent(100) - px(1) / 0.0
+    );
+    assert_eq!(None, r#in(f64::MAX).to(LengthUnit::Px));
+}
+
+#[test]
+fn checked_arithmetic() {
+    assert_eq!(Ok(px(24)), px(8).checked_mul(3.0));
+    assert_eq!(Err(NumberError::Infinite), px(1).checked_div(0.0));
+    assert_eq!(Err(NumberError::NaN), px(f64::INFINITY).checked_mul(0.0));
+    assert_eq!(
+        Ok(ms(250)),
+        s(1).checked_div(4.0)
+            .map(|time| time.to(TimeUnit::Ms).unwrap())
+    );
+}
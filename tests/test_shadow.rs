@@ -0,0 +1,97 @@
+use jss::prelude::*;
+
+#[test]
+fn box_shadow() {
+    assert_eq!("2px 4px", shadow(px(2), px(4)));
+    assert_eq!(
+        "inset 0px 2px 4px -1px rgba(0, 0, 0, 0.5)",
+        shadow(px(0), px(2))
+            .color(Color::rgba(0, 0, 0, 0.5))
+            .spread(px(-1))
+            .inset()
+            .blur(px(4))
+    );
+    assert_eq!(
+        "0px 0px 0 2px var(--focus-ring)",
+        shadow(px(0), px(0)).spread(px(2)).color(var("focus-ring"))
+    );
+    assert_eq!(
+        "0px 1px 2px black, inset 0px 0px 0 1px white, 0px 8px 16px gray",
+        shadow(px(0), px(1))
+            .blur(px(2))
+            .color("black")
+            .and(shadow(px(0), px(0)).spread(px(1)).color("white").inset())
+            .and(shadow(px(0), px(8)).blur(px(16)).color("gray"))
+    );
+    assert_eq!("none", Shadows::default());
+}
+
+#[test]
+fn text_shadow_has_no_inset_or_spread() {
+    assert_eq!("1px 1px", text_shadow(px(1), px(1)));
+    assert_eq!(
+        "0px 0px 2px black, 0px 0px 8px var(--glow)",
+        text_shadow(px(0), px(0))
+            .blur(px(2))
+            .color("black")
+            .and(text_shadow(px(0), px(0)).blur(px(8)).color(var("glow")))
+    );
+    assert_eq!("none", TextShadows::default());
+}
+
+#[test]
+fn elevation_scale() {
+    let elevation: Vec<Shadows> = (1..=3)
+        .map(|level| {
+            (1..=level)
+                .map(|layer| {
+                    shadow(px(0), px(layer * 2))
+                        .blur(px(layer * 4))
+                        .color(Color::rgba(0, 0, 0, 0.1))
+                })
+                .collect()
+        })
+        .collect();
+    assert_eq!("0px 2px 4px rgba(0, 0, 0, 0.1)", elevation[0]);
+    assert_eq!(
+        "0px 2px 4px rgba(0, 0, 0, 0.1), 0px 4px 8px rgba(0, 0, 0, 0.1), 0px 6px 12px rgba(0, 0, 0, 0.1)",
+        elevation[2]
+    );
+}
+
+#[test]
+fn filters() {
+    assert_eq!(
+        "blur(2px) brightness(0.8) contrast(1.2) grayscale(1) hue-rotate(90deg) invert(0.5) opacity(0.25) saturate(2) sepia(0.1)",
+        blur(px(2))
+            .brightness(0.8)
+            .contrast(1.2)
+            .grayscale(1.0)
+            .hue_rotate(deg(90))
+            .invert(0.5)
+            .opacity(0.25)
+            .saturate(2.0)
+            .sepia(0.1)
+    );
+    assert_eq!(
+        "drop-shadow(0px 2px 4px black) url(\"filters.svg#glow\")",
+        drop_shadow(text_shadow(px(0), px(2)).blur(px(4)).color("black")).url("filters.svg#glow")
+    );
+    assert_eq!("url(\"a.svg#b\")", filter_url("a.svg#b"));
+    assert_eq!("none", Filter::new());
+}
+
+#[test]
+fn shadow_and_filter_in_jss() {
+    let css = jss!(
+        ".dialog": {
+            box_shadow: shadow(px(0), px(4)).blur(px(8)).color("black").and(shadow(px(0), px(0)).spread(px(1)).color("gray")),
+            text_shadow: text_shadow(px(1), px(1)).color("white"),
+            backdrop_filter: blur(px(10)).saturate(1.8),
+        }
+    );
+    assert_eq!(
+        ".dialog{box-shadow:0px 4px 8px black, 0px 0px 0 1px gray;text-shadow:1px 1px white;backdrop-filter:blur(10px) saturate(1.8);}",
+        css
+    );
+}
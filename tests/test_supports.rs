@@ -0,0 +1,188 @@
+use jss::prelude::*;
+use jss::supports::{declaration, selector};
+
+fn supports_fallback() -> Config {
+    Config {
+        supports_fallback: true,
+        ..Config::pretty()
+    }
+}
+
+#[test]
+fn feature_queries() {
+    assert_eq!(
+        "(backdrop-filter: blur(4px))",
+        declaration("backdrop_filter", blur(px(4)))
+    );
+    assert_eq!("(--accent: red)", declaration("--accent", "red"));
+    assert_eq!("not selector(:has(> img))", !selector(":has(> img)"));
+    assert_eq!(
+        "@supports (display: grid) and ((gap: 1em) or (column-gap: 1em))",
+        declaration("display", "grid")
+            .and(declaration("gap", em(1)).or(declaration("column_gap", em(1))))
+            .selector()
+    );
+}
+
+#[test]
+fn nested_feature_query() {
+    let grid = declaration("display", "grid");
+    let css = jss_ns_pretty!("gallery",
+        ".": {
+            display: "flex",
+            [&grid.selector()]: {
+                display: "grid",
+            },
+        },
+        [&selector(".list:has(.item)").selector()]: {
+            ".empty": { display: "none" },
+        },
+    );
+    let expected = r#"
+.gallery {
+    display: flex;
+
+    @supports (display: grid) {
+        display: grid;
+    }
+
+}
+@supports selector(.gallery__list:has(.gallery__item)) {
+
+    .gallery__empty {
+        display: none;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn modern_declarations_with_fallback() {
+    let css = jss::process_css(
+        Some("card"),
+        &jss::json::object! {
+            ".": {
+                background_color: "white",
+                backdrop_filter: blur(px(8)),
+                container: "card / inline-size",
+            },
+        },
+        &supports_fallback(),
+    );
+    let expected = r#"
+.card {
+    background-color: white;
+}
+@supports (backdrop-filter: blur(8px)) {
+
+    .card {
+        backdrop-filter: blur(8px);
+    }
+
+}
+@supports (container: card / inline-size) {
+
+    .card {
+        container: card__card / inline-size;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn has_selector_is_wrapped() {
+    let css = jss::process_css(
+        None,
+        &jss::json::object! {
+            ".menu:has(.open), .menu:focus-within": {
+                display: "block",
+            },
+        },
+        &supports_fallback(),
+    );
+    let expected = r#"
+@supports selector(.menu:has(.open)) {
+
+    .menu:has(.open), .menu:focus-within {
+        display: block;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn fallback_values() {
+    let css = jss!(
+        ".grid": {
+            display: { fallback: "flex", supports: "grid" },
+            height: { supports: vh(100) },
+        },
+    );
+    assert_eq!(".grid{display:flex;display:grid;height:100vh;}", css);
+
+    let css = jss::process_css(
+        None,
+        &jss::json::object! {
+            ".grid": {
+                display: { fallback: "flex", supports: "grid" },
+                padding: { base: px(8), md: px(16) },
+            },
+        },
+        &supports_fallback(),
+    );
+    let expected = r#"
+.grid {
+    display: flex;
+    padding: 8px;
+}
+@media (width >= 768px) {
+
+    .grid {
+        padding: 16px;
+    }
+
+}
+@supports (display: grid) {
+
+    .grid {
+        display: grid;
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn keyframes_are_not_hoisted() {
+    let css = jss::process_css(
+        None,
+        &jss::json::object! {
+            "@keyframes frost": {
+                from: { backdrop_filter: { fallback: "none", supports: blur(px(0)) } },
+                to: { backdrop_filter: blur(px(8)) },
+            },
+        },
+        &supports_fallback(),
+    );
+    let expected = r#"
+@keyframes frost {
+
+    from {
+        backdrop-filter: none;
+        backdrop-filter: blur(0px);
+    }
+    to {
+        backdrop-filter: blur(8px);
+    }
+
+}
+"#;
+    assert_eq!(expected, css);
+}
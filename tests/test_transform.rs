@@ -0,0 +1,94 @@
+use jss::prelude::*;
+
+#[test]
+fn transform_functions() {
+    assert_eq!(
+        "translate(10px, 20px) rotate(45deg) scale(1.2)",
+        translate(px(10), px(20)).rotate(deg(45)).scale(1.2)
+    );
+    assert_eq!(
+        "translateX(50%) translateY(-1em) translateZ(0px) translate3d(1px, 2px, 3px)",
+        translate_x(percent(50))
+            .translate_y(em(-1))
+            .translate_z(px(0))
+            .translate3d(px(1), px(2), px(3))
+    );
+    assert_eq!(
+        "rotateX(10deg) rotateY(0.5turn) rotateZ(1rad) rotate3d(1, 1, 0, 30deg)",
+        rotate_x(deg(10))
+            .rotate_y(turn(0.5))
+            .rotate_z(rad(1))
+            .rotate3d(1.0, 1.0, 0.0, deg(30))
+    );
+    assert_eq!(
+        "scale(2, 0.5) scaleX(2) scaleY(3) scaleZ(4) scale3d(1, 2, 3)",
+        scale_xy(2.0, 0.5)
+            .scale_x(2.0)
+            .scale_y(3.0)
+            .scale_z(4.0)
+            .scale3d(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        "skew(10deg, 20deg) skewX(5deg) skewY(-5deg) perspective(500px)",
+        skew(deg(10), deg(20))
+            .skew_x(deg(5))
+            .skew_y(deg(-5))
+            .perspective(px(500))
+    );
+    assert_eq!(
+        "matrix(1, 0, 0, 1, 10, 20)",
+        matrix(1.0, 0.0, 0.0, 1.0, 10.0, 20.0)
+    );
+    assert_eq!("none", Transform::new());
+}
+
+#[test]
+fn reduce_to_matrix() {
+    let m = |t: Transform| t.to_matrix().map(|m| m.to_string());
+    assert_eq!(
+        Some("matrix(1, 0, 0, 1, 10, 20)".to_string()),
+        m(translate(px(10), px(20)))
+    );
+    // the translation is scaled since it comes after the scale
+    assert_eq!(
+        Some("matrix(2, 0, 0, 2, 20, 0)".to_string()),
+        m(scale(2.0).translate_x(px(10)))
+    );
+    assert_eq!(
+        Some("matrix(2, 0, 0, 2, 10, 0)".to_string()),
+        m(translate_x(px(10)).scale(2.0))
+    );
+    assert_eq!(
+        Some("matrix(0.707107, 0.707107, -0.707107, 0.707107, 0, 0)".to_string()),
+        m(rotate(deg(45)))
+    );
+    assert_eq!(
+        Some("matrix(1, 0, 1, 1, 0, 0)".to_string()),
+        m(skew_x(deg(45)))
+    );
+    assert_eq!(
+        Some("matrix(1, 0, 0, 1, 96, 0)".to_string()),
+        m(translate_x(r#in(1)))
+    );
+    assert_eq!(
+        Some("matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, -0.002, 0, 0, 0, 1)".to_string()),
+        m(perspective(px(500)))
+    );
+    assert_eq!(
+        Some("matrix3d(1, 0, 0, 0, 0, 0, 1, 0, 0, -1, 0, 0, 0, 0, 0, 1)".to_string()),
+        m(rotate_x(deg(90)))
+    );
+    // rotate3d around the z axis is the same as rotate
+    assert_eq!(m(rotate(deg(30))), m(rotate3d(0.0, 0.0, 2.0, deg(30))));
+    assert_eq!(None, m(translate(percent(50), px(0))));
+}
+
+#[test]
+fn transform_in_jss() {
+    let css = jss!(
+        ".card:hover": {
+            transform: translate_y(px(-4)).scale(1.05),
+        }
+    );
+    assert_eq!(".card:hover{transform:translateY(-4px) scale(1.05);}", css);
+}
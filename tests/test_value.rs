@@ -45,3 +45,82 @@ fn test_inch() {
     assert_eq!(r#in([1, 2, 3, 4, 5]), "1in 2in 3in 4in 5in");
     assert_eq!(r#in((1, 2, 3, 4, 5)), "1in 2in 3in 4in 5in");
 }
+
+#[test]
+fn test_grid_and_container_units() {
+    assert_eq!(fr([1, 2, 1]), "1fr 2fr 1fr");
+    assert_eq!(fr((1, 2)), "1fr 2fr");
+    assert_eq!(cqw([10, 20]), "10cqw 20cqw");
+    assert_eq!(dvh((50, 100)), "50dvh 100dvh");
+    assert_eq!(Some(dppx(1)), dpi(96).to(ResolutionUnit::Dppx));
+    assert_eq!(Some(hz(1500)), khz(1.5).to(FrequencyUnit::Hz));
+    assert_eq!("calc(100dvh - 64px)", calc(dvh(100)) - px(64));
+}
+
+#[test]
+fn test_comma_list() {
+    let css = jss!(
+        ".fade": {
+            transition: List::comma([("opacity", ms(200)), ("transform", ms(300))]),
+            font_family: List::comma([quoted("Fira Sans"), "sans-serif".to_string()]),
+            grid_area: List::slash([1, 3]),
+        }
+    );
+    let expected = r#".fade{transition:opacity 200ms, transform 300ms;font-family:"Fira Sans", sans-serif;grid-area:1 / 3;}"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_json_array_as_comma_list() {
+    let css = jss!(
+        ".fade": {
+            transition: [["opacity", ms(200)], ["transform", ms(300), "ease-in"]],
+            font_family: ["Helvetica", "sans-serif"],
+            grid_template_areas: [[quoted("header header"), quoted("nav main")]],
+        }
+    );
+    let expected = r#".fade{transition:opacity 200ms, transform 300ms ease-in;font-family:Helvetica, sans-serif;grid-template-areas:"header header" "nav main";}"#;
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_tuple_as_space_list() {
+    let css = jss!(
+        ".fade": {
+            transition: [("opacity", ms(200)), ("transform", ms(300), "ease-in")],
+            margin: (0, "auto"),
+            width: (calc(percent(100)) - px(20)),
+        }
+    );
+    let expected =
+        ".fade{transition:opacity 200ms, transform 300ms ease-in;margin:0 auto;width:calc(100% - 20px);}";
+    assert_eq!(expected, css);
+    assert_eq!(
+        "border:1px solid red;",
+        style! { border: (px(1), "solid", "red") }
+    );
+}
+
+#[test]
+fn test_quoted() {
+    assert_eq!(r#""say \"hi\"""#, quoted(r#"say "hi""#));
+    assert_eq!(r#""a\\b""#, quoted(r"a\b"));
+}
+
+#[test]
+fn test_css_functions() {
+    let css = jss!(
+        ".hero": {
+            background_image: image_set([(url("hero.jpg"), x(1)), (url("hero@2x.jpg"), x(2))]),
+            padding_top: env("safe-area-inset-top").or(px(20)),
+            width: min(percent(100) - Calc::from(var("gutter")) * 2.0, px(960)),
+            color: var("accent").or(Color::rgb(0, 128, 255)),
+        },
+        ".hero::after": {
+            content: attr("data-label"),
+        }
+    );
+    let expected = r#".hero{background-image:image-set(url("hero.jpg") 1x, url("hero@2x.jpg") 2x);padding-top:env(safe-area-inset-top, 20px);width:min(100% - 2 * var(--gutter), 960px);color:var(--accent, rgb(0, 128, 255));}.hero::after{content:attr(data-label);}"#;
+    assert_eq!(expected, css);
+    assert_eq!(r#"url("a\a b.png")"#, url("a\nb.png"));
+}